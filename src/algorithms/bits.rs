@@ -0,0 +1,133 @@
+/// Returns the number of set bits in `n`.
+///
+/// Uses Kernighan's trick: `n & (n - 1)` clears the lowest set bit, so the
+/// loop runs once per set bit.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::bits::count_set_bits;
+///
+/// assert_eq!(count_set_bits(0), 0);
+/// assert_eq!(count_set_bits(0b1011), 3);
+/// assert_eq!(count_set_bits(u64::MAX), 64);
+///
+/// for n in [0, 1, 1 << 63, 0xF0F0_F0F0_0F0F_0F0F, 0x1234_5678_9ABC_DEF0] {
+///     assert_eq!(count_set_bits(n), n.count_ones());
+/// }
+/// ```
+pub fn count_set_bits(mut n: u64) -> u32 {
+    let mut count = 0;
+    while n != 0 {
+        n &= n - 1;
+        count += 1;
+    }
+    count
+}
+
+/// Returns `true` if `n` is a power of two.
+///
+/// A power of two has exactly one set bit, so clearing the lowest set bit
+/// leaves zero.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::bits::is_power_of_two;
+///
+/// assert!(is_power_of_two(1));
+/// assert!(is_power_of_two(1 << 63));
+/// assert!(!is_power_of_two(0));
+/// assert!(!is_power_of_two(6));
+///
+/// for n in 0..5000u64 {
+///     assert_eq!(is_power_of_two(n), n.is_power_of_two());
+/// }
+/// ```
+pub fn is_power_of_two(n: u64) -> bool {
+    n != 0 && n & (n - 1) == 0
+}
+
+/// Returns the smallest power of two greater than or equal to `n`.
+///
+/// Zero maps to one. The bits below the highest set bit of `n - 1` are all
+/// filled in, and adding one carries into the next power of two.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in a `u64`, that is if `n > 1 << 63`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::bits::next_power_of_two;
+///
+/// assert_eq!(next_power_of_two(0), 1);
+/// assert_eq!(next_power_of_two(5), 8);
+/// assert_eq!(next_power_of_two(8), 8);
+/// assert_eq!(next_power_of_two(1 << 63), 1 << 63);
+///
+/// for n in 0..5000u64 {
+///     assert_eq!(next_power_of_two(n), n.next_power_of_two());
+/// }
+/// ```
+pub fn next_power_of_two(n: u64) -> u64 {
+    assert!(n <= 1 << 63, "next power of two of {n} overflows u64");
+    if n <= 1 {
+        return 1;
+    }
+    let mut m = n - 1;
+    m |= m >> 1;
+    m |= m >> 2;
+    m |= m >> 4;
+    m |= m >> 8;
+    m |= m >> 16;
+    m |= m >> 32;
+    m + 1
+}
+
+/// Returns `n` with the order of its 64 bits reversed.
+///
+/// Swaps adjacent bits, then adjacent pairs, nibbles, bytes, and so on up to
+/// the two 32-bit halves.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::bits::reverse_bits;
+///
+/// assert_eq!(reverse_bits(1), 1 << 63);
+/// assert_eq!(reverse_bits(0b1011), 0b1101 << 60);
+///
+/// for n in [0, 1, 1 << 63, 0xF0F0_F0F0_0F0F_0F0F, 0x1234_5678_9ABC_DEF0] {
+///     assert_eq!(reverse_bits(n), n.reverse_bits());
+/// }
+/// ```
+pub fn reverse_bits(mut n: u64) -> u64 {
+    n = ((n >> 1) & 0x5555_5555_5555_5555) | ((n & 0x5555_5555_5555_5555) << 1);
+    n = ((n >> 2) & 0x3333_3333_3333_3333) | ((n & 0x3333_3333_3333_3333) << 2);
+    n = ((n >> 4) & 0x0F0F_0F0F_0F0F_0F0F) | ((n & 0x0F0F_0F0F_0F0F_0F0F) << 4);
+    n = ((n >> 8) & 0x00FF_00FF_00FF_00FF) | ((n & 0x00FF_00FF_00FF_00FF) << 8);
+    n = ((n >> 16) & 0x0000_FFFF_0000_FFFF) | ((n & 0x0000_FFFF_0000_FFFF) << 16);
+    ((n >> 32) & 0x0000_0000_FFFF_FFFF) | ((n & 0x0000_0000_FFFF_FFFF) << 32)
+}
+
+/// Returns the `n`-th value of the binary reflected Gray code.
+///
+/// Consecutive Gray codes differ in exactly one bit.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::bits::gray_code;
+///
+/// let codes: Vec<u32> = (0..8).map(gray_code).collect();
+/// assert_eq!(codes, vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+///
+/// for n in 0..5000 {
+///     assert_eq!((gray_code(n) ^ gray_code(n + 1)).count_ones(), 1);
+/// }
+/// ```
+pub fn gray_code(n: u32) -> u32 {
+    n ^ (n >> 1)
+}
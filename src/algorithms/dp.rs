@@ -0,0 +1,121 @@
+/// Returns the largest sum of a non-empty contiguous subarray, together with
+/// its inclusive start and end indices.
+///
+/// Uses Kadane's algorithm in O(n). If every element is negative, the result
+/// is the single largest element. Among subarrays with the same sum, the one
+/// found first is returned.
+///
+/// # Panics
+///
+/// Panics if `slice` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::dp::max_subarray;
+///
+/// assert_eq!(max_subarray(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), (6, 3, 6));
+///
+/// // All negative: the largest single element
+/// assert_eq!(max_subarray(&[-8, -3, -6, -2, -5, -4]), (-2, 3, 3));
+///
+/// // Agrees with a brute-force search
+/// let values = [3, -4, 5, -1, 2, -6, 4, 1, -2, 3];
+/// let mut best = i64::MIN;
+/// for i in 0..values.len() {
+///     for j in i..values.len() {
+///         best = best.max(values[i..=j].iter().sum());
+///     }
+/// }
+/// let (sum, start, end) = max_subarray(&values);
+/// assert_eq!(sum, best);
+/// assert_eq!(values[start..=end].iter().sum::<i64>(), best);
+/// ```
+pub fn max_subarray(slice: &[i64]) -> (i64, usize, usize) {
+    assert!(!slice.is_empty(), "max_subarray requires a non-empty slice");
+
+    let mut best = (slice[0], 0, 0);
+    let mut current = slice[0];
+    let mut current_start = 0;
+    for (i, &value) in slice.iter().enumerate().skip(1) {
+        if current < 0 {
+            current = value;
+            current_start = i;
+        } else {
+            current += value;
+        }
+        if current > best.0 {
+            best = (current, current_start, i);
+        }
+    }
+    best
+}
+
+/// Returns one longest strictly increasing subsequence of `slice`.
+///
+/// Uses patience sorting with predecessor links in O(n log n).
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::dp::longest_increasing_subsequence;
+///
+/// let lis = longest_increasing_subsequence(&[10, 9, 2, 5, 3, 7, 101, 18]);
+/// assert_eq!(lis.len(), 4);
+/// assert!(lis.windows(2).all(|pair| pair[0] < pair[1]));
+///
+/// assert_eq!(longest_increasing_subsequence(&[5, 4, 3, 2, 1]).len(), 1);
+/// assert!(longest_increasing_subsequence::<i32>(&[]).is_empty());
+/// ```
+pub fn longest_increasing_subsequence<T: Ord + Clone>(slice: &[T]) -> Vec<T> {
+    // tails[k] is the index of the smallest value that ends an increasing
+    // subsequence of length k + 1.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessor = vec![None; slice.len()];
+    for (i, value) in slice.iter().enumerate() {
+        let position = tails.partition_point(|&t| slice[t] < *value);
+        if position > 0 {
+            predecessor[i] = Some(tails[position - 1]);
+        }
+        if position == tails.len() {
+            tails.push(i);
+        } else {
+            tails[position] = i;
+        }
+    }
+
+    let mut sequence = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        sequence.push(slice[i].clone());
+        current = predecessor[i];
+    }
+    sequence.reverse();
+    sequence
+}
+
+/// Returns the length of the longest strictly increasing subsequence of
+/// `slice` in O(n log n).
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::dp::lis_length;
+///
+/// assert_eq!(lis_length(&[10, 9, 2, 5, 3, 7, 101, 18]), 4);
+/// assert_eq!(lis_length(&[5, 4, 3, 2, 1]), 1);
+/// assert_eq!(lis_length(&[1, 1, 1]), 1);
+/// assert_eq!(lis_length::<i32>(&[]), 0);
+/// ```
+pub fn lis_length<T: Ord>(slice: &[T]) -> usize {
+    let mut tails: Vec<&T> = Vec::new();
+    for value in slice {
+        let position = tails.partition_point(|&tail| tail < value);
+        if position == tails.len() {
+            tails.push(value);
+        } else {
+            tails[position] = value;
+        }
+    }
+    tails.len()
+}
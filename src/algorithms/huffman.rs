@@ -0,0 +1,207 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(u8),
+    Internal(Box<Node>, Box<Node>),
+}
+
+/// A Huffman code tree over bytes.
+///
+/// Each byte is a leaf; its code is the path from the root, with `false`
+/// for a left edge and `true` for a right edge. No code is a prefix of
+/// another, so a bit stream decodes unambiguously. A tree with a single
+/// byte gives it the one-bit code `[false]`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::algorithms::huffman::{decode, encode};
+///
+/// let data = b"abracadabra, a bad cab";
+/// let (bits, tree) = encode(data);
+/// assert!(bits.len() < data.len() * 8);
+/// assert_eq!(decode(&bits, &tree), data);
+///
+/// // More frequent bytes never get longer codes
+/// let a = tree.code(b'a').unwrap().len();
+/// let b = tree.code(b'b').unwrap().len();
+/// let comma = tree.code(b',').unwrap().len();
+/// assert!(a <= b && b <= comma);
+/// assert_eq!(tree.code(b'z'), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HuffmanTree {
+    root: Option<Node>,
+}
+
+impl HuffmanTree {
+    /// Returns the code for `byte`, or `None` if the tree does not contain
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::huffman::build_huffman;
+    /// let tree = build_huffman(&[(b'x', 1)]);
+    /// assert_eq!(tree.code(b'x'), Some(vec![false]));
+    /// assert_eq!(tree.code(b'y'), None);
+    /// ```
+    pub fn code(&self, byte: u8) -> Option<Vec<bool>> {
+        self.codes().remove(&byte)
+    }
+
+    /// Returns the code of every byte in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::huffman::build_huffman;
+    /// let tree = build_huffman(&[(b'a', 5), (b'b', 2), (b'c', 1)]);
+    /// let codes = tree.codes();
+    /// assert_eq!(codes.len(), 3);
+    /// assert_eq!(codes[&b'a'].len(), 1);
+    /// assert_eq!(codes[&b'b'].len(), 2);
+    /// assert_eq!(codes[&b'c'].len(), 2);
+    /// ```
+    pub fn codes(&self) -> HashMap<u8, Vec<bool>> {
+        let mut codes = HashMap::new();
+        let Some(root) = &self.root else {
+            return codes;
+        };
+        if let Node::Leaf(byte) = root {
+            codes.insert(*byte, vec![false]);
+            return codes;
+        }
+        let mut stack = vec![(root, Vec::new())];
+        while let Some((node, path)) = stack.pop() {
+            match node {
+                Node::Leaf(byte) => {
+                    codes.insert(*byte, path);
+                }
+                Node::Internal(left, right) => {
+                    let mut right_path = path.clone();
+                    right_path.push(true);
+                    let mut left_path = path;
+                    left_path.push(false);
+                    stack.push((right, right_path));
+                    stack.push((left, left_path));
+                }
+            }
+        }
+        codes
+    }
+}
+
+/// Builds a Huffman tree from `(byte, frequency)` pairs.
+///
+/// The two lightest subtrees are repeatedly merged, using a
+/// [`BinaryHeap`] as a min-queue. Bytes with a frequency of zero are left
+/// out. Ties are broken by input order, so equal inputs give equal trees.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::huffman::build_huffman;
+/// let frequencies = [(b'a', 45), (b'b', 13), (b'c', 12), (b'd', 16), (b'e', 9), (b'f', 5)];
+/// let tree = build_huffman(&frequencies);
+/// let lengths: Vec<usize> = b"abcdef".iter().map(|&b| tree.code(b).unwrap().len()).collect();
+/// assert_eq!(lengths, vec![1, 3, 3, 3, 4, 4]);
+///
+/// assert!(build_huffman(&[]).codes().is_empty());
+/// ```
+pub fn build_huffman(frequencies: &[(u8, u64)]) -> HuffmanTree {
+    let mut nodes: Vec<Option<Node>> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for &(byte, frequency) in frequencies {
+        if frequency > 0 {
+            heap.push(Reverse((frequency, nodes.len())));
+            nodes.push(Some(Node::Leaf(byte)));
+        }
+    }
+    while heap.len() > 1 {
+        let Reverse((left_weight, left)) = heap.pop().unwrap();
+        let Reverse((right_weight, right)) = heap.pop().unwrap();
+        let left = nodes[left].take().unwrap();
+        let right = nodes[right].take().unwrap();
+        heap.push(Reverse((left_weight + right_weight, nodes.len())));
+        nodes.push(Some(Node::Internal(Box::new(left), Box::new(right))));
+    }
+    let root = heap
+        .pop()
+        .and_then(|Reverse((_, index))| nodes[index].take());
+    HuffmanTree { root }
+}
+
+/// Compresses `data`, returning the bit stream and the tree needed to
+/// decode it.
+///
+/// The tree is built from the byte frequencies of `data` itself.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::huffman::{decode, encode};
+/// let (bits, tree) = encode(b"aaaa");
+/// assert_eq!(bits, vec![false; 4]);
+/// assert_eq!(decode(&bits, &tree), b"aaaa");
+///
+/// let (bits, tree) = encode(b"");
+/// assert!(bits.is_empty());
+/// assert!(decode(&bits, &tree).is_empty());
+/// ```
+pub fn encode(data: &[u8]) -> (Vec<bool>, HuffmanTree) {
+    let mut counts = [0u64; 256];
+    for &byte in data {
+        counts[byte as usize] += 1;
+    }
+    let frequencies: Vec<(u8, u64)> = (0..=u8::MAX)
+        .map(|byte| (byte, counts[byte as usize]))
+        .collect();
+    let tree = build_huffman(&frequencies);
+    let codes = tree.codes();
+    let bits = data
+        .iter()
+        .flat_map(|byte| codes[byte].iter().copied())
+        .collect();
+    (bits, tree)
+}
+
+/// Decodes a bit stream produced with `tree`.
+///
+/// Trailing bits that do not complete a code are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::huffman::{build_huffman, decode};
+/// let tree = build_huffman(&[(b'a', 2), (b'b', 1)]);
+/// let a = tree.code(b'a').unwrap();
+/// let b = tree.code(b'b').unwrap();
+/// let bits: Vec<bool> = [&b, &a, &a].into_iter().flatten().copied().collect();
+/// assert_eq!(decode(&bits, &tree), b"baa");
+/// ```
+pub fn decode(bits: &[bool], tree: &HuffmanTree) -> Vec<u8> {
+    let mut output = Vec::new();
+    let Some(root) = &tree.root else {
+        return output;
+    };
+    if let Node::Leaf(byte) = root {
+        output.resize(bits.len(), *byte);
+        return output;
+    }
+    let mut node = root;
+    for &bit in bits {
+        if let Node::Internal(left, right) = node {
+            node = if bit { right } else { left };
+        }
+        if let Node::Leaf(byte) = node {
+            output.push(*byte);
+            node = root;
+        }
+    }
+    output
+}
@@ -0,0 +1,82 @@
+/// Returns a maximum matching in a bipartite graph.
+///
+/// The graph has `left` vertices `0..left` on one side, `right` vertices
+/// `0..right` on the other, and `edges` given as `(left_vertex,
+/// right_vertex)` pairs. Uses Kuhn's augmenting-path algorithm in
+/// O(V * E). The matched pairs are returned in ascending order of left
+/// vertex.
+///
+/// # Panics
+///
+/// Panics if an edge refers to a vertex outside its side.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::matching::max_bipartite_matching;
+///
+/// // A perfect matching exists
+/// let edges = [(0, 0), (0, 1), (1, 0), (2, 1), (2, 2)];
+/// let matching = max_bipartite_matching(3, 3, &edges);
+/// assert_eq!(matching.len(), 3);
+/// for pair in &matching {
+///     assert!(edges.contains(pair));
+/// }
+///
+/// // Three left vertices compete for two right vertices
+/// let edges = [(0, 0), (1, 0), (2, 0), (2, 1)];
+/// assert_eq!(max_bipartite_matching(3, 2, &edges).len(), 2);
+///
+/// assert!(max_bipartite_matching(2, 2, &[]).is_empty());
+/// ```
+pub fn max_bipartite_matching(
+    left: usize,
+    right: usize,
+    edges: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    let mut adjacency = vec![Vec::new(); left];
+    for &(u, v) in edges {
+        assert!(u < left, "left vertex {u} out of range");
+        assert!(v < right, "right vertex {v} out of range");
+        adjacency[u].push(v);
+    }
+
+    let mut match_of_right: Vec<Option<usize>> = vec![None; right];
+    for u in 0..left {
+        let mut visited = vec![false; right];
+        try_augment(u, &adjacency, &mut visited, &mut match_of_right);
+    }
+
+    let mut matching: Vec<(usize, usize)> = match_of_right
+        .iter()
+        .enumerate()
+        .filter_map(|(v, &u)| u.map(|u| (u, v)))
+        .collect();
+    matching.sort_unstable();
+    matching
+}
+
+/// Looks for an augmenting path starting at left vertex `u`, flipping the
+/// matching along it if one is found.
+fn try_augment(
+    u: usize,
+    adjacency: &[Vec<usize>],
+    visited: &mut [bool],
+    match_of_right: &mut [Option<usize>],
+) -> bool {
+    for &v in &adjacency[u] {
+        if visited[v] {
+            continue;
+        }
+        visited[v] = true;
+        let free = match match_of_right[v] {
+            None => true,
+            Some(other) => try_augment(other, adjacency, visited, match_of_right),
+        };
+        if free {
+            match_of_right[v] = Some(u);
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Caches the results of a pure function, keyed by its arguments.
+///
+/// The wrapped function receives the memoizer itself as its first argument,
+/// so recursive calls can go through [`call`](Self::call) and share the
+/// cache. Hit and miss counts are recorded for inspection.
+///
+/// The function is a plain `fn` pointer; closures that capture nothing
+/// coerce to one. Use a tuple for `Args` when there are several arguments.
+///
+/// # Examples
+///
+/// A memoized Fibonacci runs in linear time:
+///
+/// ```
+/// use algo_rust::algorithms::memoize::Memoize;
+///
+/// let mut fib = Memoize::new(|fib, n: u64| {
+///     if n < 2 { n } else { fib.call(n - 1) + fib.call(n - 2) }
+/// });
+///
+/// assert_eq!(fib.call(50), 12_586_269_025);
+///
+/// // Each of fib(0)..=fib(50) is computed exactly once, and every
+/// // fib(n - 2) for n in 3..=50 is answered from the cache.
+/// assert_eq!(fib.misses(), 51);
+/// assert_eq!(fib.hits(), 48);
+///
+/// // Calling again hits the cache straight away
+/// assert_eq!(fib.call(50), 12_586_269_025);
+/// assert_eq!(fib.hits(), 49);
+/// ```
+pub struct Memoize<Args, Ret> {
+    func: fn(&mut Memoize<Args, Ret>, Args) -> Ret,
+    cache: HashMap<Args, Ret>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<Args: Hash + Eq + Clone, Ret: Clone> Memoize<Args, Ret> {
+    /// Creates a memoizer for `func` with an empty cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::memoize::Memoize;
+    /// let square = Memoize::new(|_, x: i32| x * x);
+    /// assert_eq!(square.hits(), 0);
+    /// assert_eq!(square.misses(), 0);
+    /// ```
+    pub fn new(func: fn(&mut Memoize<Args, Ret>, Args) -> Ret) -> Self {
+        Memoize {
+            func,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the result for `args`, computing and caching it on a miss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::memoize::Memoize;
+    /// let mut add = Memoize::new(|_, (a, b): (i32, i32)| a + b);
+    /// assert_eq!(add.call((2, 3)), 5);
+    /// assert_eq!(add.call((2, 3)), 5);
+    /// assert_eq!(add.misses(), 1);
+    /// assert_eq!(add.hits(), 1);
+    /// ```
+    pub fn call(&mut self, args: Args) -> Ret {
+        if let Some(result) = self.cache.get(&args) {
+            self.hits += 1;
+            return result.clone();
+        }
+        self.misses += 1;
+        let result = (self.func)(self, args.clone());
+        self.cache.insert(args, result.clone());
+        result
+    }
+
+    /// Returns the number of calls answered from the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::memoize::Memoize;
+    /// let mut square = Memoize::new(|_, x: i32| x * x);
+    /// square.call(3);
+    /// square.call(3);
+    /// assert_eq!(square.hits(), 1);
+    /// ```
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of calls that had to run the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::memoize::Memoize;
+    /// let mut square = Memoize::new(|_, x: i32| x * x);
+    /// square.call(3);
+    /// square.call(4);
+    /// assert_eq!(square.misses(), 2);
+    /// ```
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Empties the cache and resets the hit and miss counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::memoize::Memoize;
+    /// let mut square = Memoize::new(|_, x: i32| x * x);
+    /// square.call(3);
+    /// square.clear();
+    /// square.call(3);
+    /// assert_eq!(square.misses(), 1);
+    /// assert_eq!(square.hits(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
@@ -0,0 +1,263 @@
+pub mod bits;
+pub mod dp;
+pub mod huffman;
+pub mod matching;
+pub mod memoize;
+pub mod number_theory;
+pub mod random;
+pub mod sorting;
+pub mod stats;
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+/// Returns the element that occurs more than `slice.len() / 2` times, if
+/// there is one.
+///
+/// Uses the Boyer-Moore voting algorithm to find the only possible candidate
+/// in one pass, then verifies it with a second pass. Runs in O(n) time and
+/// O(1) extra space.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::majority_element;
+///
+/// assert_eq!(majority_element(&[2, 2, 1, 1, 2, 2, 3]), Some(2));
+/// assert_eq!(majority_element(&["a"]), Some("a"));
+///
+/// // No element occurs more than half the time
+/// assert_eq!(majority_element(&[1, 2, 3, 1, 2]), None);
+/// assert_eq!(majority_element(&[1, 1, 2, 2]), None);
+///
+/// let empty: [i32; 0] = [];
+/// assert_eq!(majority_element(&empty), None);
+/// ```
+pub fn majority_element<T: PartialEq + Clone>(slice: &[T]) -> Option<T> {
+    let mut candidate = None;
+    let mut votes = 0usize;
+    for value in slice {
+        if votes == 0 {
+            candidate = Some(value);
+            votes = 1;
+        } else if candidate == Some(value) {
+            votes += 1;
+        } else {
+            votes -= 1;
+        }
+    }
+
+    let candidate = candidate?;
+    let count = slice.iter().filter(|&value| value == candidate).count();
+    (count > slice.len() / 2).then(|| candidate.clone())
+}
+
+/// An error returned by [`from_base`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The base is outside `2..=36`.
+    InvalidBase(u32),
+    /// The input string is empty.
+    Empty,
+    /// The character is not a digit in the requested base.
+    InvalidDigit(char),
+    /// The value does not fit in a `u64`.
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidBase(base) => write!(f, "base {base} is not in 2..=36"),
+            ParseError::Empty => write!(f, "cannot parse an empty string"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit {c:?}"),
+            ParseError::Overflow => write!(f, "number too large to fit in u64"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Formats `n` in the given base using the digits `0-9a-z`.
+///
+/// # Panics
+///
+/// Panics if `base` is not in `2..=36`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::to_base;
+///
+/// assert_eq!(to_base(0, 2), "0");
+/// assert_eq!(to_base(10, 2), "1010");
+/// assert_eq!(to_base(255, 16), "ff");
+/// assert_eq!(to_base(35, 36), "z");
+/// ```
+pub fn to_base(mut n: u64, base: u32) -> String {
+    assert!((2..=36).contains(&base), "base {base} is not in 2..=36");
+    if n == 0 {
+        return "0".to_string();
+    }
+    let base = u64::from(base);
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push(DIGITS[(n % base) as usize]);
+        n /= base;
+    }
+    digits.iter().rev().map(|&d| d as char).collect()
+}
+
+/// Parses a string of digits `0-9a-z` in the given base.
+///
+/// Letters may be upper or lower case.
+///
+/// # Errors
+///
+/// Returns an error if `base` is not in `2..=36`, if `s` is empty, if any
+/// character is not a digit in `base`, or if the value overflows a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::{ParseError, from_base, to_base};
+///
+/// assert_eq!(from_base("1010", 2), Ok(10));
+/// assert_eq!(from_base("FF", 16), Ok(255));
+///
+/// // Round trip through every base
+/// for base in 2..=36 {
+///     for n in [0, 1, 35, 36, 1295, 123_456_789, u64::MAX] {
+///         assert_eq!(from_base(&to_base(n, base), base), Ok(n));
+///     }
+/// }
+///
+/// // Invalid input
+/// assert_eq!(from_base("12", 2), Err(ParseError::InvalidDigit('2')));
+/// assert_eq!(from_base("1-1", 10), Err(ParseError::InvalidDigit('-')));
+/// assert_eq!(from_base("", 10), Err(ParseError::Empty));
+/// assert_eq!(from_base("10", 1), Err(ParseError::InvalidBase(1)));
+/// assert_eq!(from_base("10", 37), Err(ParseError::InvalidBase(37)));
+/// assert_eq!(from_base("10000000000000000", 16), Err(ParseError::Overflow));
+/// ```
+pub fn from_base(s: &str, base: u32) -> Result<u64, ParseError> {
+    if !(2..=36).contains(&base) {
+        return Err(ParseError::InvalidBase(base));
+    }
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    s.chars().try_fold(0u64, |value, c| {
+        let digit = c.to_digit(base).ok_or(ParseError::InvalidDigit(c))?;
+        value
+            .checked_mul(u64::from(base))
+            .and_then(|value| value.checked_add(u64::from(digit)))
+            .ok_or(ParseError::Overflow)
+    })
+}
+
+/// Returns the maximum of every contiguous window of `k` elements.
+///
+/// A monotonic deque holds the indices of window elements in decreasing
+/// order of value, so each index is pushed and popped at most once and the
+/// whole pass runs in O(n). The result has `slice.len() - k + 1` entries.
+///
+/// # Panics
+///
+/// Panics if `k` is zero or larger than `slice.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::sliding_window_max;
+///
+/// assert_eq!(
+///     sliding_window_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+///     vec![3, 3, 5, 5, 6, 7]
+/// );
+///
+/// // Agrees with a naive per-window maximum, across rising and falling runs
+/// let values = [4, -2, 7, 7, 0, -5, -6, 3, 9, 1, 1, 8];
+/// for k in [1, 2, 4, 12] {
+///     let fast = sliding_window_max(&values, k);
+///     let naive: Vec<i64> = values.windows(k).map(|w| *w.iter().max().unwrap()).collect();
+///     assert_eq!(fast.len(), values.len() - k + 1);
+///     assert_eq!(fast, naive);
+/// }
+/// ```
+pub fn sliding_window_max(slice: &[i64], k: usize) -> Vec<i64> {
+    assert!(k > 0, "window size must be positive");
+    assert!(
+        k <= slice.len(),
+        "window size {k} exceeds slice length {}",
+        slice.len()
+    );
+
+    let mut window: VecDeque<usize> = VecDeque::with_capacity(k);
+    let mut maxima = Vec::with_capacity(slice.len() - k + 1);
+    for (i, &value) in slice.iter().enumerate() {
+        if window.front().is_some_and(|&front| front + k <= i) {
+            window.pop_front();
+        }
+        while window.back().is_some_and(|&back| slice[back] <= value) {
+            window.pop_back();
+        }
+        window.push_back(i);
+        if i + 1 >= k {
+            maxima.push(slice[window[0]]);
+        }
+    }
+    maxima
+}
+
+/// Merges overlapping closed intervals into a sorted list of disjoint ones.
+///
+/// Each `(start, end)` pair is the closed interval `[start, end]`. The
+/// input is sorted in place by start. Intervals that share an endpoint,
+/// such as `(1, 2)` and `(2, 3)`, touch and are merged; `(1, 2)` and
+/// `(3, 4)` have a gap between them and stay separate.
+///
+/// # Panics
+///
+/// Panics if any interval has `start > end`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::merge_intervals;
+///
+/// // Overlapping
+/// assert_eq!(merge_intervals(&mut [(1, 3), (2, 6), (8, 10)]), vec![(1, 6), (8, 10)]);
+///
+/// // Nested
+/// assert_eq!(merge_intervals(&mut [(1, 10), (2, 3), (4, 5)]), vec![(1, 10)]);
+///
+/// // Touching
+/// assert_eq!(merge_intervals(&mut [(2, 3), (1, 2)]), vec![(1, 3)]);
+///
+/// // Disjoint, given out of order
+/// assert_eq!(merge_intervals(&mut [(5, 6), (1, 2), (3, 4)]), vec![(1, 2), (3, 4), (5, 6)]);
+///
+/// assert!(merge_intervals(&mut []).is_empty());
+/// ```
+pub fn merge_intervals(intervals: &mut [(i64, i64)]) -> Vec<(i64, i64)> {
+    for &(start, end) in intervals.iter() {
+        assert!(
+            start <= end,
+            "interval ({start}, {end}) starts after it ends"
+        );
+    }
+    intervals.sort_unstable();
+
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for &(start, end) in intervals.iter() {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
@@ -0,0 +1,314 @@
+use crate::data_structs::bitset::BitSet;
+
+/// Returns the prime factorization of `n` as `(prime, exponent)` pairs in
+/// ascending order of prime.
+///
+/// Uses trial division up to `sqrt(n)`. Both `0` and `1` have no prime
+/// factors and return an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::number_theory::prime_factorization;
+///
+/// assert_eq!(prime_factorization(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(prime_factorization(97), vec![(97, 1)]);
+/// assert!(prime_factorization(1).is_empty());
+/// ```
+pub fn prime_factorization(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n == 0 {
+        return factors;
+    }
+    let mut p = 2;
+    while p <= n / p {
+        if n.is_multiple_of(p) {
+            let mut exponent = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Returns Euler's totient of `n`: how many integers in `1..=n` are coprime
+/// to `n`.
+///
+/// Computed from the prime factorization as `n * prod(1 - 1/p)`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::number_theory::euler_totient;
+///
+/// // A prime p has p - 1 smaller coprime numbers
+/// for p in [2, 3, 5, 7, 11, 13, 97] {
+///     assert_eq!(euler_totient(p), p - 1);
+/// }
+/// assert_eq!(euler_totient(1), 1);
+/// assert_eq!(euler_totient(36), 12);
+/// ```
+pub fn euler_totient(n: u64) -> u64 {
+    prime_factorization(n)
+        .into_iter()
+        .fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+/// Returns every positive divisor of `n` in ascending order.
+///
+/// Zero is divisible by every integer, so `divisors(0)` returns an empty
+/// vector rather than an unbounded one.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::number_theory::divisors;
+///
+/// assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+/// assert_eq!(divisors(49), vec![1, 7, 49]);
+/// assert_eq!(divisors(1), vec![1]);
+/// assert!(divisors(0).is_empty());
+/// ```
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+    let mut d = 1;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            small.push(d);
+            if d != n / d {
+                large.push(n / d);
+            }
+        }
+        d += 1;
+    }
+    small.extend(large.into_iter().rev());
+    small
+}
+
+/// Returns `(g, x, y)` such that `g = gcd(a, b)` and `a * x + b * y = g`.
+///
+/// Uses the extended Euclidean algorithm. `g` is non-negative.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::number_theory::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+///
+/// let (g, x, y) = extended_gcd(-12, 18);
+/// assert_eq!(g, 6);
+/// assert_eq!(-12 * x + 18 * y, 6);
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// Solves the system of congruences `x ≡ residues[i] (mod moduli[i])`.
+///
+/// Returns `(x, m)` where `m` is the least common multiple of the moduli and
+/// `x` is the unique solution in `0..m`, or `None` if the congruences
+/// contradict each other. The moduli need not be pairwise coprime. An empty
+/// system returns `(0, 1)`.
+///
+/// # Panics
+///
+/// Panics if the slices differ in length, if any modulus is not positive,
+/// or if the least common multiple overflows an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::number_theory::crt;
+///
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+/// assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+///
+/// // Non-coprime but consistent moduli
+/// assert_eq!(crt(&[1, 3], &[4, 6]), Some((9, 12)));
+///
+/// // x ≡ 1 (mod 4) and x ≡ 2 (mod 6) disagree modulo 2
+/// assert_eq!(crt(&[1, 2], &[4, 6]), None);
+/// ```
+pub fn crt(residues: &[i64], moduli: &[i64]) -> Option<(i64, i64)> {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "residues and moduli must have the same length"
+    );
+
+    let (mut x, mut m): (i128, i128) = (0, 1);
+    for (&r, &n) in residues.iter().zip(moduli) {
+        assert!(n > 0, "modulus {n} is not positive");
+        let (r, n) = (i128::from(r).rem_euclid(i128::from(n)), i128::from(n));
+
+        // Solve x + m * k ≡ r (mod n) for k.
+        let (g, inverse, _) = extended_gcd((m % n) as i64, n as i64);
+        let (g, inverse) = (i128::from(g), i128::from(inverse));
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let k = (diff / g % step * inverse).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        assert!(m <= i128::from(i64::MAX), "combined modulus overflows i64");
+        x = x.rem_euclid(m);
+    }
+    Some((x as i64, m as i64))
+}
+
+/// A growable sieve of Eratosthenes that answers primality and n-th prime
+/// queries.
+///
+/// Only odd numbers are stored: bit `i` of a [`BitSet`] is set when
+/// `2 * i + 1` is prime, which halves the memory of a plain sieve. A query
+/// past the sieved range re-sieves up to at least double the old limit,
+/// so a run of increasing queries costs amortized linear time overall.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::algorithms::number_theory::PrimeCache;
+///
+/// let mut primes = PrimeCache::new();
+/// assert!(primes.is_prime(2));
+/// assert!(primes.is_prime(97));
+/// assert!(!primes.is_prime(91));
+/// assert_eq!(primes.nth_prime(0), 2);
+/// assert_eq!(primes.nth_prime(1), 3);
+/// assert_eq!(primes.nth_prime(999), 7919);
+///
+/// // Compared against trial division
+/// fn is_prime_naive(n: u64) -> bool {
+///     n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)
+/// }
+/// for n in 0..5000 {
+///     assert_eq!(primes.is_prime(n), is_prime_naive(n), "n = {n}");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrimeCache {
+    odd_primes: BitSet,
+    limit: u64,
+}
+
+impl Default for PrimeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrimeCache {
+    /// Creates a cache with a small initial sieve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::number_theory::PrimeCache;
+    /// let mut primes = PrimeCache::new();
+    /// assert!(primes.is_prime(13));
+    /// ```
+    pub fn new() -> Self {
+        let mut cache = PrimeCache {
+            odd_primes: BitSet::new(),
+            limit: 0,
+        };
+        cache.sieve(64);
+        cache
+    }
+
+    /// Returns `true` if `n` is prime, extending the sieve if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::number_theory::PrimeCache;
+    /// let mut primes = PrimeCache::new();
+    /// assert!(!primes.is_prime(0));
+    /// assert!(!primes.is_prime(1));
+    /// assert!(primes.is_prime(1_000_003));
+    /// assert!(!primes.is_prime(1_000_001));
+    /// ```
+    pub fn is_prime(&mut self, n: u64) -> bool {
+        if n < 3 {
+            return n == 2;
+        }
+        if n.is_multiple_of(2) {
+            return false;
+        }
+        if n > self.limit {
+            self.sieve(n.max(2 * self.limit));
+        }
+        self.odd_primes.get((n / 2) as usize)
+    }
+
+    /// Returns the `k`-th prime, counting from zero, extending the sieve
+    /// until it contains that many primes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::number_theory::PrimeCache;
+    /// let mut primes = PrimeCache::new();
+    /// let first: Vec<u64> = (0..8).map(|k| primes.nth_prime(k)).collect();
+    /// assert_eq!(first, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+    /// assert_eq!(primes.nth_prime(9_999), 104_729);
+    /// ```
+    pub fn nth_prime(&mut self, k: usize) -> u64 {
+        if k == 0 {
+            return 2;
+        }
+        loop {
+            if let Some(index) = self.odd_primes.iter().nth(k - 1) {
+                return 2 * index as u64 + 1;
+            }
+            self.sieve(2 * self.limit);
+        }
+    }
+
+    fn sieve(&mut self, limit: u64) {
+        let mut odd_primes = BitSet::new();
+        let odd_count = (limit as usize).div_ceil(2);
+        for i in 1..odd_count {
+            odd_primes.set(i);
+        }
+        let mut p = 3;
+        while p <= limit / p {
+            if odd_primes.get((p / 2) as usize) {
+                for multiple in (p * p..=limit).step_by(2 * p as usize) {
+                    odd_primes.clear((multiple / 2) as usize);
+                }
+            }
+            p += 2;
+        }
+        self.odd_primes = odd_primes;
+        self.limit = limit;
+    }
+}
@@ -0,0 +1,117 @@
+use crate::rng;
+
+/// Returns an integer uniformly distributed in `0..bound`.
+fn next_below(state: &mut u64, bound: u64) -> u64 {
+    ((u128::from(rng::next(state)) * u128::from(bound)) >> 64) as u64
+}
+
+/// Returns a float uniformly distributed in `[0, 1)`.
+fn next_f64(state: &mut u64) -> f64 {
+    (rng::next(state) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Picks an index with probability proportional to its weight.
+///
+/// Draws one uniform number from a generator seeded with `seed` and finds
+/// it by binary search in the cumulative weights, in O(n). The same
+/// weights and seed always give the same index. Indices with zero weight
+/// are never picked.
+///
+/// # Panics
+///
+/// Panics if any weight is negative or not finite, or if the weights do not
+/// sum to a positive value.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::random::weighted_sample;
+///
+/// let weights = [1.0, 0.0, 3.0, 6.0];
+/// assert_eq!(weighted_sample(&weights, 42), weighted_sample(&weights, 42));
+///
+/// // The empirical distribution approaches the weights
+/// let samples = 100_000;
+/// let mut counts = [0usize; 4];
+/// for seed in 0..samples {
+///     counts[weighted_sample(&weights, seed)] += 1;
+/// }
+/// assert_eq!(counts[1], 0);
+/// for (count, weight) in counts.iter().zip(weights) {
+///     let observed = *count as f64 / samples as f64;
+///     assert!((observed - weight / 10.0).abs() < 0.01);
+/// }
+/// ```
+pub fn weighted_sample(weights: &[f64], seed: u64) -> usize {
+    let mut cumulative = Vec::with_capacity(weights.len());
+    let mut total = 0.0;
+    for &weight in weights {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "weight {weight} is not a non-negative number"
+        );
+        total += weight;
+        cumulative.push(total);
+    }
+    assert!(total > 0.0, "weights must have a positive sum");
+
+    let mut state = seed;
+    let target = next_f64(&mut state) * total;
+    match cumulative.partition_point(|&sum| sum <= target) {
+        // `target` rounded up to exactly `total`: take the last index that
+        // can be picked at all.
+        index if index == weights.len() => weights.iter().rposition(|&w| w > 0.0).unwrap(),
+        index => index,
+    }
+}
+
+/// Selects `k` items uniformly at random from `iter` in a single pass.
+///
+/// Uses reservoir sampling (Algorithm R): the first `k` items fill the
+/// reservoir, and the `i`-th item after that replaces a random slot with
+/// probability `k / (i + 1)`. The stream length need not be known in
+/// advance, and the whole stream is always consumed. The result holds
+/// `min(k, stream length)` items; the same stream and seed always give the
+/// same result.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::random::reservoir_sample;
+///
+/// assert_eq!(reservoir_sample(0..100, 5, 7).len(), 5);
+/// assert_eq!(reservoir_sample(0..3, 5, 7), vec![0, 1, 2]);
+/// assert!(reservoir_sample(0..100, 0, 7).is_empty());
+///
+/// // Deterministic for a fixed seed, and every element is considered
+/// let mut seen = 0;
+/// let sample = reservoir_sample((0..1000).inspect(|_| seen += 1), 10, 99);
+/// assert_eq!(seen, 1000);
+/// assert_eq!(sample, reservoir_sample(0..1000, 10, 99));
+///
+/// // Each element is about equally likely to be chosen
+/// let mut counts = [0usize; 10];
+/// for seed in 0..20_000 {
+///     for item in reservoir_sample(0..10, 3, seed) {
+///         counts[item] += 1;
+///     }
+/// }
+/// for count in counts {
+///     assert!((count as f64 / 20_000.0 - 0.3).abs() < 0.02);
+/// }
+/// ```
+pub fn reservoir_sample<T, I: Iterator<Item = T>>(iter: I, k: usize, seed: u64) -> Vec<T> {
+    let mut state = seed;
+    let mut reservoir = Vec::new();
+    for (i, item) in iter.enumerate() {
+        if i < k {
+            reservoir.push(item);
+        } else {
+            let j = next_below(&mut state, i as u64 + 1) as usize;
+            if j < k {
+                reservoir[j] = item;
+            }
+        }
+    }
+    reservoir
+}
@@ -0,0 +1,112 @@
+/// Moves the element at `index` down a max-heap until neither child is
+/// larger.
+///
+/// The slice is a binary heap in array layout: the children of `i` are
+/// `2 * i + 1` and `2 * i + 2`. Both subtrees of `index` must already be
+/// valid heaps.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::sorting::sift_down;
+/// let mut heap = [1, 9, 8, 5, 4];
+/// sift_down(&mut heap, 0);
+/// assert_eq!(heap, [9, 5, 8, 1, 4]);
+/// ```
+pub fn sift_down<T: Ord>(slice: &mut [T], mut index: usize) {
+    loop {
+        let left = 2 * index + 1;
+        let right = left + 1;
+        let mut largest = index;
+        if left < slice.len() && slice[left] > slice[largest] {
+            largest = left;
+        }
+        if right < slice.len() && slice[right] > slice[largest] {
+            largest = right;
+        }
+        if largest == index {
+            return;
+        }
+        slice.swap(index, largest);
+        index = largest;
+    }
+}
+
+/// Moves the element at `index` up a max-heap until its parent is not
+/// smaller.
+///
+/// Everything outside the path from `index` to the root must already be a
+/// valid heap. This is the step used after appending to a heap.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::sorting::sift_up;
+/// let mut heap = [9, 5, 8, 1, 4, 10];
+/// sift_up(&mut heap, 5);
+/// assert_eq!(heap, [10, 5, 9, 1, 4, 8]);
+/// ```
+pub fn sift_up<T: Ord>(slice: &mut [T], mut index: usize) {
+    while index > 0 {
+        let parent = (index - 1) / 2;
+        if slice[parent] >= slice[index] {
+            return;
+        }
+        slice.swap(parent, index);
+        index = parent;
+    }
+}
+
+/// Rearranges `slice` into a max-heap in O(n).
+///
+/// Sifts down every internal node, starting from the last one.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::sorting::heapify;
+/// let mut values = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// heapify(&mut values);
+/// for i in 1..values.len() {
+///     assert!(values[(i - 1) / 2] >= values[i]);
+/// }
+/// assert_eq!(values[0], 9);
+/// ```
+pub fn heapify<T: Ord>(slice: &mut [T]) {
+    for index in (0..slice.len() / 2).rev() {
+        sift_down(slice, index);
+    }
+}
+
+/// Sorts `slice` in ascending order in place using heap sort.
+///
+/// Builds a max-heap, then repeatedly swaps the maximum to the end of the
+/// unsorted region. Runs in O(n log n) and is not stable.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::algorithms::sorting::heap_sort;
+///
+/// let mut values = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// heap_sort(&mut values);
+/// assert_eq!(values, [1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9]);
+///
+/// let mut descending: Vec<u32> = (0..100).rev().collect();
+/// heap_sort(&mut descending);
+/// assert!(descending.into_iter().eq(0..100));
+///
+/// let mut equal = [7; 5];
+/// heap_sort(&mut equal);
+/// assert_eq!(equal, [7; 5]);
+///
+/// let mut empty: [i32; 0] = [];
+/// heap_sort(&mut empty);
+/// ```
+pub fn heap_sort<T: Ord>(slice: &mut [T]) {
+    heapify(slice);
+    for end in (1..slice.len()).rev() {
+        slice.swap(0, end);
+        sift_down(&mut slice[..end], 0);
+    }
+}
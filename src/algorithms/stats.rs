@@ -0,0 +1,137 @@
+use std::collections::VecDeque;
+
+/// The mean of the most recent values in a stream.
+///
+/// The last `window` values are kept in a ring buffer alongside their
+/// running sum, so each update adds the new value and subtracts the one
+/// falling out of the window in O(1). Before the window fills, the average
+/// is over every value seen so far.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::algorithms::stats::MovingAverage;
+///
+/// let mut average = MovingAverage::new(3);
+/// assert_eq!(average.push(3.0), 3.0);
+/// assert_eq!(average.push(6.0), 4.5);
+/// assert_eq!(average.push(9.0), 6.0);
+/// // 3.0 falls out of the window
+/// assert_eq!(average.push(0.0), 5.0);
+///
+/// // Compared against a naive mean over the window at every step
+/// let mut values = Vec::new();
+/// let mut average = MovingAverage::new(3);
+/// for value in [1e6, -1e6, 2.5, 7.5, 0.0, -4.0, 1e6, 0.5] {
+///     values.push(value);
+///     let window = &values[values.len().saturating_sub(3)..];
+///     let naive = window.iter().sum::<f64>() / window.len() as f64;
+///     assert!((average.push(value) - naive).abs() < 1e-6);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MovingAverage {
+    window: usize,
+    values: VecDeque<f64>,
+    sum: f64,
+}
+
+impl MovingAverage {
+    /// Creates an empty moving average over the last `window` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::stats::MovingAverage;
+    /// let average = MovingAverage::new(4);
+    /// assert_eq!(average.average(), None);
+    /// ```
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window size must be positive");
+        MovingAverage {
+            window,
+            values: VecDeque::with_capacity(window),
+            sum: 0.0,
+        }
+    }
+
+    /// Adds a value to the stream and returns the new average.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::stats::MovingAverage;
+    /// let mut average = MovingAverage::new(2);
+    /// average.push(1.0);
+    /// average.push(2.0);
+    /// assert_eq!(average.push(4.0), 3.0);
+    /// ```
+    pub fn push(&mut self, value: f64) -> f64 {
+        if self.values.len() == self.window
+            && let Some(oldest) = self.values.pop_front()
+        {
+            self.sum -= oldest;
+        }
+        self.values.push_back(value);
+        self.sum += value;
+        self.sum / self.values.len() as f64
+    }
+
+    /// Returns the current average, or `None` if no values have been
+    /// pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::stats::MovingAverage;
+    /// let mut average = MovingAverage::new(3);
+    /// assert_eq!(average.average(), None);
+    /// average.push(2.0);
+    /// average.push(4.0);
+    /// assert_eq!(average.average(), Some(3.0));
+    /// ```
+    pub fn average(&self) -> Option<f64> {
+        if self.values.is_empty() {
+            None
+        } else {
+            Some(self.sum / self.values.len() as f64)
+        }
+    }
+
+    /// Returns the number of values currently in the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::stats::MovingAverage;
+    /// let mut average = MovingAverage::new(2);
+    /// for value in [1.0, 2.0, 3.0] {
+    ///     average.push(value);
+    /// }
+    /// assert_eq!(average.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::algorithms::stats::MovingAverage;
+    /// let mut average = MovingAverage::new(2);
+    /// assert!(average.is_empty());
+    /// average.push(1.0);
+    /// assert!(!average.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
@@ -0,0 +1,236 @@
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A growable set of bits backed by 64-bit words.
+///
+/// Bit `i` lives in word `i / 64`. Setting a bit past the end grows the
+/// storage; reading or clearing one never does.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::bitset::BitSet;
+///
+/// let mut bits = BitSet::new();
+///
+/// // Set bits on both sides of a word boundary
+/// bits.set(3);
+/// bits.set(63);
+/// bits.set(64);
+/// assert_eq!(bits.count_ones(), 3);
+///
+/// // Clear a bit
+/// bits.clear(63);
+/// assert!(!bits.get(63));
+/// assert!(bits.get(64));
+///
+/// // Iterate over set bits
+/// assert_eq!(bits.iter().collect::<Vec<_>>(), vec![3, 64]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Creates an empty `BitSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::bitset::BitSet;
+    /// let bits = BitSet::new();
+    /// assert_eq!(bits.count_ones(), 0);
+    /// ```
+    pub fn new() -> Self {
+        BitSet { words: Vec::new() }
+    }
+
+    /// Sets bit `i`, growing the set if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::bitset::BitSet;
+    /// let mut bits = BitSet::new();
+    /// bits.set(1000);
+    /// assert!(bits.get(1000));
+    /// ```
+    pub fn set(&mut self, i: usize) {
+        let word = i / WORD_BITS;
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= 1 << (i % WORD_BITS);
+    }
+
+    /// Clears bit `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::bitset::BitSet;
+    /// let mut bits = BitSet::new();
+    /// bits.set(5);
+    /// bits.clear(5);
+    /// bits.clear(500);
+    /// assert!(!bits.get(5));
+    /// ```
+    pub fn clear(&mut self, i: usize) {
+        if let Some(word) = self.words.get_mut(i / WORD_BITS) {
+            *word &= !(1 << (i % WORD_BITS));
+        }
+    }
+
+    /// Returns `true` if bit `i` is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::bitset::BitSet;
+    /// let mut bits = BitSet::new();
+    /// bits.set(7);
+    /// assert!(bits.get(7));
+    /// assert!(!bits.get(8));
+    /// assert!(!bits.get(10_000));
+    /// ```
+    pub fn get(&self, i: usize) -> bool {
+        self.words
+            .get(i / WORD_BITS)
+            .is_some_and(|word| word & (1 << (i % WORD_BITS)) != 0)
+    }
+
+    /// Returns the number of set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::bitset::BitSet;
+    /// let mut bits = BitSet::new();
+    /// for i in (0..200).step_by(3) {
+    ///     bits.set(i);
+    /// }
+    /// assert_eq!(bits.count_ones(), 67);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns a new set containing the bits set in either set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use algo_rust::data_structs::bitset::BitSet;
+    ///
+    /// let a_items = [1, 5, 64, 130];
+    /// let b_items = [2, 5, 65, 130, 300];
+    /// let mut a = BitSet::new();
+    /// let mut b = BitSet::new();
+    /// a_items.iter().for_each(|&i| a.set(i));
+    /// b_items.iter().for_each(|&i| b.set(i));
+    ///
+    /// let a_ref: HashSet<usize> = a_items.into_iter().collect();
+    /// let b_ref: HashSet<usize> = b_items.into_iter().collect();
+    /// let mut expected: Vec<usize> = a_ref.union(&b_ref).copied().collect();
+    /// expected.sort();
+    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (word, &other) in words.iter_mut().zip(&shorter.words) {
+            *word |= other;
+        }
+        BitSet { words }
+    }
+
+    /// Returns a new set containing the bits set in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use algo_rust::data_structs::bitset::BitSet;
+    ///
+    /// let a_items = [1, 5, 64, 130];
+    /// let b_items = [2, 5, 65, 130, 300];
+    /// let mut a = BitSet::new();
+    /// let mut b = BitSet::new();
+    /// a_items.iter().for_each(|&i| a.set(i));
+    /// b_items.iter().for_each(|&i| b.set(i));
+    ///
+    /// let a_ref: HashSet<usize> = a_items.into_iter().collect();
+    /// let b_ref: HashSet<usize> = b_items.into_iter().collect();
+    /// let mut expected: Vec<usize> = a_ref.intersection(&b_ref).copied().collect();
+    /// expected.sort();
+    /// assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| a & b)
+            .collect();
+        BitSet { words }
+    }
+
+    /// Returns a new set containing the bits set in `self` but not in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use algo_rust::data_structs::bitset::BitSet;
+    ///
+    /// let a_items = [1, 5, 64, 130, 400];
+    /// let b_items = [2, 5, 65, 130];
+    /// let mut a = BitSet::new();
+    /// let mut b = BitSet::new();
+    /// a_items.iter().for_each(|&i| a.set(i));
+    /// b_items.iter().for_each(|&i| b.set(i));
+    ///
+    /// let a_ref: HashSet<usize> = a_items.into_iter().collect();
+    /// let b_ref: HashSet<usize> = b_items.into_iter().collect();
+    /// let mut expected: Vec<usize> = a_ref.difference(&b_ref).copied().collect();
+    /// expected.sort();
+    /// assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), expected);
+    /// ```
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        let mut words = self.words.clone();
+        for (word, &other) in words.iter_mut().zip(&other.words) {
+            *word &= !other;
+        }
+        BitSet { words }
+    }
+
+    /// Returns an iterator over the indices of the set bits in ascending
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::bitset::BitSet;
+    /// let mut bits = BitSet::new();
+    /// bits.set(70);
+    /// bits.set(2);
+    /// assert_eq!(bits.iter().collect::<Vec<_>>(), vec![2, 70]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            (0..WORD_BITS)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| index * WORD_BITS + bit)
+        })
+    }
+}
@@ -0,0 +1,277 @@
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    next: usize,
+}
+
+/// A singly-linked list whose last node links back to the first.
+///
+/// Nodes live in a `Vec` and link to each other by index, which lets the
+/// tail point back at the head without shared ownership or `unsafe`. Slots
+/// freed by [`pop`](Self::pop) are reused by later pushes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::circular::CircularLinkedList;
+///
+/// let mut list = CircularLinkedList::new();
+///
+/// // Push elements
+/// list.push(3);
+/// list.push(2);
+/// list.push(1);
+/// assert_eq!(list.length(), 3);
+///
+/// // Each element once
+/// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+///
+/// // Round and round
+/// assert_eq!(
+///     list.cycle_iter().take(2 * list.length()).collect::<Vec<_>>(),
+///     vec![&1, &2, &3, &1, &2, &3]
+/// );
+///
+/// // Pop from the front
+/// assert_eq!(list.pop(), Some(1));
+/// assert_eq!(list.peek(), Some(&2));
+/// ```
+#[derive(Debug)]
+pub struct CircularLinkedList<T> {
+    nodes: Vec<Option<Node<T>>>,
+    free: Vec<usize>,
+    tail: Option<usize>,
+    length: usize,
+}
+
+impl<T> CircularLinkedList<T> {
+    /// Creates an empty `CircularLinkedList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let list: CircularLinkedList<i32> = CircularLinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        CircularLinkedList {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            tail: None,
+            length: 0,
+        }
+    }
+
+    /// Adds an element to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// assert_eq!(list.peek(), Some(&2));
+    /// ```
+    pub fn push(&mut self, value: T) {
+        let index = self.free.pop().unwrap_or(self.nodes.len());
+        let next = match self.tail {
+            Some(tail) => {
+                let tail = self.nodes[tail].as_mut().unwrap();
+                std::mem::replace(&mut tail.next, index)
+            }
+            None => {
+                self.tail = Some(index);
+                index
+            }
+        };
+        let node = Some(Node { value, next });
+        if index == self.nodes.len() {
+            self.nodes.push(node);
+        } else {
+            self.nodes[index] = node;
+        }
+        self.length += 1;
+    }
+
+    /// Removes and returns the element at the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// assert_eq!(list.pop(), Some(2));
+    /// assert_eq!(list.pop(), Some(1));
+    /// assert_eq!(list.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let head = self.node(tail).next;
+        let node = self.nodes[head].take().unwrap();
+        if head == tail {
+            self.tail = None;
+        } else {
+            self.nodes[tail].as_mut().unwrap().next = node.next;
+        }
+        self.free.push(head);
+        self.length -= 1;
+        Some(node.value)
+    }
+
+    /// Returns a reference to the element at the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// assert_eq!(list.peek(), None);
+    /// list.push(1);
+    /// assert_eq!(list.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.head().map(|head| &self.node(head).value)
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// assert_eq!(list.length(), 0);
+    /// list.push(1);
+    /// assert_eq!(list.length(), 1);
+    /// ```
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the list contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// assert!(list.is_empty());
+    /// list.push(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Creates an iterator that yields each element once, starting at the
+    /// front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(2);
+    /// list.push(1);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            next: self.head(),
+            remaining: self.length,
+        }
+    }
+
+    /// Creates an iterator that follows the links around the list forever,
+    /// starting at the front.
+    ///
+    /// The iterator ends immediately only if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::circular::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(2);
+    /// list.push(1);
+    /// assert_eq!(
+    ///     list.cycle_iter().take(5).collect::<Vec<_>>(),
+    ///     vec![&1, &2, &1, &2, &1]
+    /// );
+    ///
+    /// let empty: CircularLinkedList<i32> = CircularLinkedList::new();
+    /// assert_eq!(empty.cycle_iter().next(), None);
+    /// ```
+    pub fn cycle_iter(&self) -> CycleIter<'_, T> {
+        CycleIter {
+            list: self,
+            next: self.head(),
+        }
+    }
+
+    fn head(&self) -> Option<usize> {
+        self.tail.map(|tail| self.node(tail).next)
+    }
+
+    fn node(&self, index: usize) -> &Node<T> {
+        self.nodes[index].as_ref().unwrap()
+    }
+}
+
+impl<T> Default for CircularLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over each element of a circular list once.
+pub struct Iter<'a, T> {
+    list: &'a CircularLinkedList<T>,
+    next: Option<usize>,
+    remaining: usize,
+}
+
+/// An endless iterator around a circular list.
+pub struct CycleIter<'a, T> {
+    list: &'a CircularLinkedList<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.next.map(|index| {
+            let node = self.list.node(index);
+            self.next = Some(node.next);
+            &node.value
+        })
+    }
+}
+
+impl<'a, T> Iterator for CycleIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|index| {
+            let node = self.list.node(index);
+            self.next = Some(node.next);
+            &node.value
+        })
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A first-in, first-out queue that can be shared between threads.
+///
+/// The elements are kept in a [`VecDeque`] guarded by a [`Mutex`], so every
+/// operation takes `&self` and holds the lock for O(1) work.
+/// `ConcurrentQueue<T>` is `Send` and `Sync` whenever `T` is `Send`.
+///
+/// # Examples
+///
+/// Producers and consumers sharing one queue:
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+/// use algo_rust::data_structs::concurrent::ConcurrentQueue;
+///
+/// const PRODUCERS: usize = 4;
+/// const CONSUMERS: usize = 3;
+/// const PER_PRODUCER: usize = 100;
+/// const TOTAL: usize = PRODUCERS * PER_PRODUCER;
+///
+/// let queue = Arc::new(ConcurrentQueue::new());
+/// let received = Arc::new(AtomicUsize::new(0));
+///
+/// let producers: Vec<_> = (0..PRODUCERS)
+///     .map(|p| {
+///         let queue = Arc::clone(&queue);
+///         thread::spawn(move || {
+///             for i in 0..PER_PRODUCER {
+///                 queue.enqueue(p * PER_PRODUCER + i);
+///             }
+///         })
+///     })
+///     .collect();
+///
+/// let consumers: Vec<_> = (0..CONSUMERS)
+///     .map(|_| {
+///         let queue = Arc::clone(&queue);
+///         let received = Arc::clone(&received);
+///         thread::spawn(move || {
+///             let mut items = Vec::new();
+///             while received.load(Ordering::SeqCst) < TOTAL {
+///                 match queue.dequeue() {
+///                     Some(item) => {
+///                         items.push(item);
+///                         received.fetch_add(1, Ordering::SeqCst);
+///                     }
+///                     None => thread::yield_now(),
+///                 }
+///             }
+///             items
+///         })
+///     })
+///     .collect();
+///
+/// for producer in producers {
+///     producer.join().unwrap();
+/// }
+/// let mut all: Vec<usize> = consumers
+///     .into_iter()
+///     .flat_map(|consumer| consumer.join().unwrap())
+///     .collect();
+///
+/// // Every item arrives exactly once.
+/// all.sort();
+/// assert_eq!(all, (0..TOTAL).collect::<Vec<_>>());
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentQueue<T> {
+    items: Mutex<VecDeque<T>>,
+}
+
+impl<T> ConcurrentQueue<T> {
+    /// Creates an empty `ConcurrentQueue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::concurrent::ConcurrentQueue;
+    /// let queue: ConcurrentQueue<i32> = ConcurrentQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        ConcurrentQueue {
+            items: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Adds an element to the back of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    pub fn enqueue(&self, value: T) {
+        self.lock().push_back(value);
+    }
+
+    /// Removes and returns the element at the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    pub fn dequeue(&self) -> Option<T> {
+        self.lock().pop_front()
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    /// assert_eq!(queue.len(), 0);
+    /// queue.enqueue(1);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::concurrent::ConcurrentQueue;
+    /// let queue = ConcurrentQueue::new();
+    /// assert!(queue.is_empty());
+    /// queue.enqueue(1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    // No operation can panic while holding the lock, so a poisoned mutex
+    // still guards a consistent queue.
+    fn lock(&self) -> MutexGuard<'_, VecDeque<T>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Default for ConcurrentQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,208 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A multiset that counts how many times each item was added.
+///
+/// Counts are kept in a `HashMap`. Items whose count drops to zero are
+/// removed entirely.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::counter::Counter;
+///
+/// let counter: Counter<char> = "mississippi".chars().collect();
+///
+/// assert_eq!(counter.count(&'s'), 4);
+/// assert_eq!(counter.count(&'m'), 1);
+/// assert_eq!(counter.count(&'z'), 0);
+/// assert_eq!(counter.len(), 4);
+///
+/// // Most common items come first
+/// let common = counter.most_common(3);
+/// assert_eq!(common[0].1, 4);
+/// assert_eq!(common[1].1, 4);
+/// assert_eq!(common[2], (&'p', 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Counter<T> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    /// Creates an empty `Counter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let counter: Counter<i32> = Counter::new();
+    /// assert!(counter.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Adds one occurrence of `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let mut counter = Counter::new();
+    /// counter.add("a");
+    /// counter.add("a");
+    /// assert_eq!(counter.count(&"a"), 2);
+    /// ```
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    /// Adds `n` occurrences of `item`.
+    ///
+    /// Adding zero occurrences leaves the counter unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let mut counter = Counter::new();
+    /// counter.add_n("a", 3);
+    /// counter.add_n("b", 0);
+    /// assert_eq!(counter.count(&"a"), 3);
+    /// assert_eq!(counter.len(), 1);
+    /// ```
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_insert(0) += n;
+        }
+    }
+
+    /// Returns how many times `item` has been added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let mut counter = Counter::new();
+    /// counter.add(1);
+    /// assert_eq!(counter.count(&1), 1);
+    /// assert_eq!(counter.count(&2), 0);
+    /// ```
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Removes one occurrence of `item`, returning `true` if it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let mut counter = Counter::new();
+    /// counter.add_n("a", 2);
+    /// assert!(counter.remove(&"a"));
+    /// assert_eq!(counter.count(&"a"), 1);
+    /// assert!(counter.remove(&"a"));
+    /// assert!(!counter.remove(&"a"));
+    /// assert!(counter.is_empty());
+    /// ```
+    pub fn remove(&mut self, item: &T) -> bool {
+        match self.counts.get_mut(item) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                true
+            }
+            Some(_) => {
+                self.counts.remove(item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns up to `k` items with their counts, most frequent first.
+    ///
+    /// Items with equal counts are ordered by their position in
+    /// `HashMap` iteration, which is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let counter: Counter<i32> = [3, 1, 3, 2, 3, 1].into_iter().collect();
+    /// assert_eq!(counter.most_common(2), vec![(&3, 3), (&1, 2)]);
+    /// assert_eq!(counter.most_common(10).len(), 3);
+    /// ```
+    pub fn most_common(&self, k: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_by_key(|&(_, count)| Reverse(count));
+        items.truncate(k);
+        items
+    }
+
+    /// Returns the number of distinct items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let counter: Counter<i32> = [1, 1, 2].into_iter().collect();
+    /// assert_eq!(counter.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if no items have been counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let mut counter = Counter::new();
+    /// assert!(counter.is_empty());
+    /// counter.add(1);
+    /// assert!(!counter.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Creates an iterator over each distinct item and its count, in
+    /// unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::counter::Counter;
+    /// let counter: Counter<i32> = [1, 1, 2].into_iter().collect();
+    /// let mut items: Vec<_> = counter.iter().collect();
+    /// items.sort();
+    /// assert_eq!(items, vec![(&1, 2), (&2, 1)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        for item in iter {
+            counter.add(item);
+        }
+        counter
+    }
+}
@@ -0,0 +1,124 @@
+/// An array of zeros that accumulates range additions offline.
+///
+/// Adding `delta` to `l..=r` only touches two entries of a difference array:
+/// `+delta` at `l` and `-delta` just past `r`. The final values are the
+/// prefix sums of those entries, computed once by
+/// [`finalize`](Self::finalize). Updates are O(1) and finalizing is O(n).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::difference::DifferenceArray;
+///
+/// let mut diff = DifferenceArray::new(6);
+/// diff.range_add(0, 2, 5);
+/// diff.range_add(1, 4, -2);
+/// diff.range_add(4, 5, 10);
+/// assert_eq!(diff.finalize(), vec![5, 3, 3, -2, 8, 10]);
+///
+/// // Ranges touching either end, and updates that cancel out
+/// let mut diff = DifferenceArray::new(4);
+/// diff.range_add(0, 3, 1);
+/// diff.range_add(3, 3, 7);
+/// diff.range_add(1, 2, 4);
+/// diff.range_add(1, 2, -4);
+/// assert_eq!(diff.finalize(), vec![1, 1, 1, 8]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DifferenceArray {
+    deltas: Vec<i64>,
+}
+
+impl DifferenceArray {
+    /// Creates a difference array over `len` zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::difference::DifferenceArray;
+    /// let diff = DifferenceArray::new(3);
+    /// assert_eq!(diff.finalize(), vec![0, 0, 0]);
+    /// ```
+    pub fn new(len: usize) -> Self {
+        DifferenceArray {
+            deltas: vec![0; len + 1],
+        }
+    }
+
+    /// Adds `delta` to every element at indices `l..=r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `l > r` or `r` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::difference::DifferenceArray;
+    /// let mut diff = DifferenceArray::new(4);
+    /// diff.range_add(1, 2, 7);
+    /// diff.range_add(2, 3, 1);
+    /// assert_eq!(diff.finalize(), vec![0, 7, 8, 1]);
+    /// ```
+    pub fn range_add(&mut self, l: usize, r: usize, delta: i64) {
+        assert!(l <= r, "range start {l} is after its end {r}");
+        assert!(
+            r < self.len(),
+            "index {r} out of bounds for length {}",
+            self.len()
+        );
+        self.deltas[l] += delta;
+        self.deltas[r + 1] -= delta;
+    }
+
+    /// Returns the array with every range addition applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::difference::DifferenceArray;
+    /// let mut diff = DifferenceArray::new(3);
+    /// diff.range_add(0, 2, 1);
+    /// assert_eq!(diff.finalize(), vec![1, 1, 1]);
+    ///
+    /// // Further updates build on the earlier ones
+    /// diff.range_add(1, 1, 4);
+    /// assert_eq!(diff.finalize(), vec![1, 5, 1]);
+    /// ```
+    pub fn finalize(&self) -> Vec<i64> {
+        self.deltas[..self.len()]
+            .iter()
+            .scan(0, |sum, delta| {
+                *sum += delta;
+                Some(*sum)
+            })
+            .collect()
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::difference::DifferenceArray;
+    /// assert_eq!(DifferenceArray::new(5).len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.deltas.len() - 1
+    }
+
+    /// Returns `true` if the array has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::difference::DifferenceArray;
+    /// assert!(DifferenceArray::new(0).is_empty());
+    /// assert!(!DifferenceArray::new(1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
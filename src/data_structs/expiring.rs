@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// A source of the current time for [`ExpiringMap`].
+///
+/// [`SystemClock`] reads the real monotonic clock. Tests can provide their
+/// own implementation to control time explicitly.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// A [`Clock`] backed by [`Instant::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug)]
+struct Entry<V> {
+    value: V,
+    /// `None` if the TTL reaches past the latest representable instant, in
+    /// which case the entry never expires.
+    expires_at: Option<Instant>,
+}
+
+impl<V> Entry<V> {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// A hash map whose entries expire after a time-to-live.
+///
+/// Expired entries are never returned. They are evicted lazily when looked
+/// up, or all at once with [`purge_expired`](Self::purge_expired).
+///
+/// # Examples
+///
+/// Using a fake clock to control time:
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use std::time::{Duration, Instant};
+/// use algo_rust::data_structs::expiring::{Clock, ExpiringMap};
+///
+/// #[derive(Clone)]
+/// struct FakeClock(Rc<Cell<Instant>>);
+///
+/// impl Clock for FakeClock {
+///     fn now(&self) -> Instant {
+///         self.0.get()
+///     }
+/// }
+///
+/// let time = Rc::new(Cell::new(Instant::now()));
+/// let mut map = ExpiringMap::with_clock(FakeClock(Rc::clone(&time)));
+///
+/// map.insert("short", 1, Duration::from_secs(5));
+/// map.insert("long", 2, Duration::from_secs(60));
+/// assert_eq!(map.get(&"short"), Some(&1));
+///
+/// // Advance past the first TTL only
+/// time.set(time.get() + Duration::from_secs(10));
+/// assert_eq!(map.get(&"short"), None);
+/// assert_eq!(map.get(&"long"), Some(&2));
+/// assert_eq!(map.len(), 1);
+///
+/// // Advance past the second TTL
+/// time.set(time.get() + Duration::from_secs(60));
+/// assert_eq!(map.get(&"long"), None);
+/// assert!(map.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct ExpiringMap<K, V, C = SystemClock> {
+    entries: HashMap<K, Entry<V>>,
+    clock: C,
+}
+
+impl<K: Hash + Eq, V> ExpiringMap<K, V> {
+    /// Creates an empty `ExpiringMap` using the system clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let map: ExpiringMap<&str, i32> = ExpiringMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<K: Hash + Eq, V> Default for ExpiringMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq, V, C: Clock> ExpiringMap<K, V, C> {
+    /// Creates an empty `ExpiringMap` that reads time from `clock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::expiring::{ExpiringMap, SystemClock};
+    /// let map: ExpiringMap<&str, i32> = ExpiringMap::with_clock(SystemClock);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn with_clock(clock: C) -> Self {
+        ExpiringMap {
+            entries: HashMap::new(),
+            clock,
+        }
+    }
+
+    /// Inserts a value that expires `ttl` from now.
+    ///
+    /// Returns the previous value for `key` if it had not yet expired. A
+    /// `ttl` too large to add to the current instant, such as
+    /// [`Duration::MAX`], means the entry never expires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let mut map = ExpiringMap::new();
+    /// assert_eq!(map.insert("a", 1, Duration::from_secs(60)), None);
+    /// assert_eq!(map.insert("a", 2, Duration::from_secs(60)), Some(1));
+    /// assert_eq!(map.get(&"a"), Some(&2));
+    ///
+    /// map.insert("forever", 3, Duration::MAX);
+    /// map.purge_expired();
+    /// assert_eq!(map.get(&"forever"), Some(&3));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        let now = self.clock.now();
+        let entry = Entry {
+            value,
+            expires_at: now.checked_add(ttl),
+        };
+        self.entries
+            .insert(key, entry)
+            .filter(|old| !old.is_expired(now))
+            .map(|old| old.value)
+    }
+
+    /// Returns a reference to the value for `key` if it has not expired.
+    ///
+    /// An expired entry is evicted by the lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let mut map = ExpiringMap::new();
+    /// map.insert("a", 1, Duration::from_secs(60));
+    /// map.insert("b", 2, Duration::ZERO);
+    /// assert_eq!(map.get(&"a"), Some(&1));
+    /// assert_eq!(map.get(&"b"), None);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let now = self.clock.now();
+        if self
+            .entries
+            .get(key)
+            .is_some_and(|entry| entry.is_expired(now))
+        {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Removes `key` and returns its value if it had not expired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let mut map = ExpiringMap::new();
+    /// map.insert("a", 1, Duration::from_secs(60));
+    /// assert_eq!(map.remove(&"a"), Some(1));
+    /// assert_eq!(map.remove(&"a"), None);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let now = self.clock.now();
+        self.entries
+            .remove(key)
+            .filter(|entry| !entry.is_expired(now))
+            .map(|entry| entry.value)
+    }
+
+    /// Evicts every expired entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let mut map = ExpiringMap::new();
+    /// map.insert("a", 1, Duration::from_secs(60));
+    /// map.insert("b", 2, Duration::ZERO);
+    /// assert_eq!(map.len(), 2);
+    /// map.purge_expired();
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn purge_expired(&mut self) {
+        let now = self.clock.now();
+        self.entries.retain(|_, entry| !entry.is_expired(now));
+    }
+
+    /// Returns the number of stored entries.
+    ///
+    /// Entries that have expired but not yet been evicted are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let mut map = ExpiringMap::new();
+    /// assert_eq!(map.len(), 0);
+    /// map.insert("a", 1, Duration::from_secs(60));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map stores no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use algo_rust::data_structs::expiring::ExpiringMap;
+    /// let mut map = ExpiringMap::new();
+    /// assert!(map.is_empty());
+    /// map.insert("a", 1, Duration::from_secs(60));
+    /// assert!(!map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// A set of points stored as sorted, disjoint half-open intervals.
+///
+/// Intervals are kept in a `BTreeMap` from start to end. Inserting merges
+/// the new interval with every interval it overlaps or touches, so the
+/// stored intervals never share a point and are never adjacent. Removing
+/// cuts a range out, splitting an interval in two if needed. Empty ranges
+/// are ignored by both.
+///
+/// # Examples
+///
+/// Booking time slots:
+///
+/// ```
+/// use algo_rust::data_structs::interval_set::IntervalSet;
+///
+/// let mut booked = IntervalSet::new();
+/// booked.insert(9..11);
+/// booked.insert(10..12); // overlaps and merges
+/// booked.insert(12..13); // touches and merges
+/// booked.insert(15..17);
+/// assert_eq!(booked.iter().collect::<Vec<_>>(), vec![9..13, 15..17]);
+///
+/// // Cancel a middle chunk, splitting the booking
+/// booked.remove(10..11);
+/// assert_eq!(booked.iter().collect::<Vec<_>>(), vec![9..10, 11..13, 15..17]);
+///
+/// assert!(booked.contains(9));
+/// assert!(!booked.contains(10));
+/// assert!(booked.contains(12));
+/// assert!(!booked.contains(13));
+/// assert!(!booked.contains(14));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IntervalSet {
+    intervals: BTreeMap<i64, i64>,
+}
+
+impl IntervalSet {
+    /// Creates an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let set = IntervalSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: BTreeMap::new(),
+        }
+    }
+
+    /// Adds every point in `interval` to the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let mut set = IntervalSet::new();
+    /// set.insert(1..3);
+    /// set.insert(5..7);
+    /// set.insert(0..10); // swallows both
+    /// set.insert(4..4); // empty, ignored
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..10]);
+    /// ```
+    pub fn insert(&mut self, interval: Range<i64>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (interval.start, interval.end);
+        let touching: Vec<(i64, i64)> = self
+            .intervals
+            .range(..=end)
+            .rev()
+            .take_while(|&(_, &e)| e >= start)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        for (s, e) in touching {
+            self.intervals.remove(&s);
+            start = start.min(s);
+            end = end.max(e);
+        }
+        self.intervals.insert(start, end);
+    }
+
+    /// Removes every point in `interval` from the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let mut set = IntervalSet::new();
+    /// set.insert(0..5);
+    /// set.insert(8..12);
+    /// set.remove(3..10);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..3, 10..12]);
+    ///
+    /// set.remove(-5..100);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn remove(&mut self, interval: Range<i64>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (start, end) = (interval.start, interval.end);
+        let overlapping: Vec<(i64, i64)> = self
+            .intervals
+            .range(..end)
+            .rev()
+            .take_while(|&(_, &e)| e > start)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        for (s, e) in overlapping {
+            self.intervals.remove(&s);
+            if s < start {
+                self.intervals.insert(s, start);
+            }
+            if e > end {
+                self.intervals.insert(end, e);
+            }
+        }
+    }
+
+    /// Returns `true` if `point` lies in one of the intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let mut set = IntervalSet::new();
+    /// set.insert(2..4);
+    /// assert!(!set.contains(1));
+    /// assert!(set.contains(2));
+    /// assert!(set.contains(3));
+    /// assert!(!set.contains(4));
+    /// ```
+    pub fn contains(&self, point: i64) -> bool {
+        self.intervals
+            .range(..=point)
+            .next_back()
+            .is_some_and(|(_, &end)| point < end)
+    }
+
+    /// Returns the number of disjoint intervals in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let mut set = IntervalSet::new();
+    /// set.insert(0..2);
+    /// set.insert(4..6);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns `true` if the set contains no points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let mut set = IntervalSet::new();
+    /// assert!(set.is_empty());
+    /// set.insert(0..1);
+    /// assert!(!set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Creates an iterator over the intervals in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::interval_set::IntervalSet;
+    /// let mut set = IntervalSet::new();
+    /// set.insert(5..6);
+    /// set.insert(1..2);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..2, 5..6]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        self.intervals.iter().map(|(&start, &end)| start..end)
+    }
+}
@@ -1,4 +1,11 @@
 
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::Sum;
+
 #[derive(Debug)]
 struct Node<T> {
     pub value: T,
@@ -63,6 +70,34 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Creates a `LinkedList` holding the items of `iter` in the same order.
+    ///
+    /// Each item is appended after the previous one, so iterating the list
+    /// yields the items in source order. Repeated [`push`](Self::push) calls
+    /// would reverse them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back(1..=5);
+    /// assert_eq!(list.length(), 5);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+    ///
+    /// let empty = LinkedList::from_iter_back(Vec::<i32>::new());
+    /// assert!(empty.is_empty());
+    /// ```
+    pub fn from_iter_back<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        let mut tail = &mut list.head;
+        for value in iter {
+            let node = tail.insert(Box::new(Node::new(value, None)));
+            tail = &mut node.next;
+            list.length += 1;
+        }
+        list
+    }
+
     /// Adds an element to the front of the list.
     ///
     /// # Examples
@@ -166,145 +201,1442 @@ impl<T> LinkedList<T> {
         self.length == 0
     }
 
-    /// Clears the list, removing all elements.
+    /// Returns a reference to the largest element, or `None` if the list is
+    /// empty.
+    ///
+    /// If several elements are equally large, the last one is returned. The
+    /// name avoids clashing with [`Ord::max`], which compares two lists.
     ///
     /// # Examples
     ///
     /// ```
     /// use algo_rust::data_structs::linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push(1);
-    /// list.push(2);
+    /// let list = LinkedList::from_iter_back([3, 1, 4, 1, 5]);
+    /// assert_eq!(list.max_element(), Some(&5));
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert_eq!(empty.max_element(), None);
+    /// ```
+    #[doc(alias = "max")]
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns a reference to the smallest element, or `None` if the list is
+    /// empty.
+    ///
+    /// If several elements are equally small, the first one is returned. The
+    /// name avoids clashing with [`Ord::min`], which compares two lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([3, 1, 4, 1, 5]);
+    /// assert_eq!(list.min_element(), Some(&1));
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert_eq!(empty.min_element(), None);
+    /// ```
+    #[doc(alias = "min")]
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns the sum of all elements.
+    ///
+    /// An empty list sums to the zero value of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([3, 1, 4, 1, 5]);
+    /// assert_eq!(list.sum(), 14);
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert_eq!(empty.sum(), 0);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Sum + Copy,
+    {
+        self.iter().copied().sum()
+    }
+
+    /// Interprets the list as a binary number, with the head as the most
+    /// significant bit.
+    ///
+    /// An empty list is 0. Leading zeros are allowed in any number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element is neither 0 nor 1, or if the value does not
+    /// fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// assert_eq!(LinkedList::from_iter_back([1u8, 0, 1]).to_decimal(), 5);
+    /// assert_eq!(LinkedList::from_iter_back([0u8, 0, 1, 1]).to_decimal(), 3);
+    /// assert_eq!(LinkedList::<u8>::new().to_decimal(), 0);
+    /// assert_eq!(LinkedList::from_iter_back([1u8; 64]).to_decimal(), u64::MAX);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// LinkedList::from_iter_back([1u8, 2, 0]).to_decimal();
+    /// ```
+    pub fn to_decimal(&self) -> u64
+    where
+        T: Into<u64> + Copy,
+    {
+        self.iter().fold(0, |value, &bit| {
+            let bit: u64 = bit.into();
+            assert!(bit <= 1, "element {bit} is not a binary digit");
+            value
+                .checked_mul(2)
+                .expect("binary number does not fit in a u64")
+                | bit
+        })
+    }
+
+    /// Returns a list of the running accumulator states produced by folding
+    /// `f` over the elements, front to back.
+    ///
+    /// The `i`-th element of the result is the accumulator after the first
+    /// `i + 1` elements, so the result has the same length as `self` and
+    /// `init` itself is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4]);
+    ///
+    /// let prefix_sums = list.running_fold(0, |acc, &x| acc + x);
+    /// assert_eq!(prefix_sums.length(), list.length());
+    /// assert_eq!(prefix_sums.iter().collect::<Vec<_>>(), vec![&1, &3, &6, &10]);
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert!(empty.running_fold(0, |acc, &x| acc + x).is_empty());
+    /// ```
+    pub fn running_fold<B: Clone, F: FnMut(&B, &T) -> B>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> LinkedList<B> {
+        LinkedList::from_iter_back(self.iter().scan(init, |acc, value| {
+            *acc = f(acc, value);
+            Some(acc.clone())
+        }))
+    }
+
+    /// Folds the elements from the tail towards the head, so `f` first sees
+    /// the last element.
+    ///
+    /// For `[a, b, c]` this computes `f(a, f(b, f(c, init)))`. The list is
+    /// singly linked, so references to the elements are collected into a
+    /// `Vec` first; this uses O(n) extra space but no recursion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4]);
+    ///
+    /// // Pushing while folding from the right builds the reversed order
+    /// let reversed = list.fold_right(Vec::new(), |&x, mut acc| {
+    ///     acc.push(x);
+    ///     acc
+    /// });
+    /// assert_eq!(reversed, vec![4, 3, 2, 1]);
+    ///
+    /// // Right-associative subtraction: 1 - (2 - (3 - (4 - 0)))
+    /// assert_eq!(list.fold_right(0, |&x, acc| x - acc), -2);
+    ///
+    /// let nested = list.fold_right(String::from("nil"), |x, acc| format!("({x} {acc})"));
+    /// assert_eq!(nested, "(1 (2 (3 (4 nil))))");
+    /// ```
+    pub fn fold_right<B, F: FnMut(&T, B) -> B>(&self, init: B, mut f: F) -> B {
+        let values: Vec<&T> = self.iter().collect();
+        values
+            .into_iter()
+            .rev()
+            .fold(init, |acc, value| f(value, acc))
+    }
+
+    /// Shortens the list to its first `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to the current
+    /// length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, 2, 3, 4, 5]);
+    ///
+    /// list.truncate(10);
+    /// assert_eq!(list.length(), 5);
+    ///
+    /// list.truncate(2);
     /// assert_eq!(list.length(), 2);
-    /// list.clear();
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    ///
+    /// list.truncate(0);
     /// assert!(list.is_empty());
     /// ```
-    pub fn clear(&mut self) {
-        *self = Self::new();
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+        let mut link = &mut self.head;
+        for _ in 0..len {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        let mut current = link.take();
+        while let Some(node) = current {
+            current = node.next;
+        }
+        self.length = len;
     }
 
-    /// Creates an iterator that consumes the list.
+    /// Resizes the list to `new_len` elements.
+    ///
+    /// If the list grows, values produced by `f` are appended to the back,
+    /// after the existing elements. If it shrinks, it is truncated as by
+    /// [`truncate`](Self::truncate).
     ///
     /// # Examples
     ///
     /// ```
     /// use algo_rust::data_structs::linked_list::LinkedList;
     /// let mut list = LinkedList::new();
-    /// list.push(1);
-    /// list.push(2);
+    /// let mut counter = 0;
+    /// list.resize_with(3, || {
+    ///     counter += 1;
+    ///     counter
+    /// });
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
     ///
-    /// let mut iter = list.into_iter();
-    /// assert_eq!(iter.next(), Some(2));
-    /// assert_eq!(iter.next(), Some(1));
-    /// assert_eq!(iter.next(), None);
+    /// list.resize_with(5, || 0);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &0, &0]);
+    ///
+    /// list.resize_with(2, || unreachable!());
+    /// assert_eq!(list.length(), 2);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2]);
     /// ```
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter { list: self }
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+        let mut tail = &mut self.head;
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+        for _ in self.length..new_len {
+            let node = tail.insert(Box::new(Node::new(f(), None)));
+            tail = &mut node.next;
+        }
+        self.length = new_len;
     }
 
-    /// Creates an iterator over the list's elements.
+    /// Moves every element of `other` into the list, starting at `index`.
+    ///
+    /// Elements at or after `index` are shifted back to follow the spliced
+    /// ones. Nodes are relinked rather than cloned; the cost is a walk to
+    /// `index` plus a walk over `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.length()`.
     ///
     /// # Examples
     ///
     /// ```
     /// use algo_rust::data_structs::linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push(1);
-    /// list.push(2);
+    /// let mut list = LinkedList::from_iter_back([1, 2, 3]);
     ///
-    /// let mut iter = list.iter();
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), Some(&1));
-    /// assert_eq!(iter.next(), None);
+    /// list.splice(0, LinkedList::from_iter_back([-1, 0]));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&-1, &0, &1, &2, &3]);
+    ///
+    /// list.splice(5, LinkedList::from_iter_back([4]));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&-1, &0, &1, &2, &3, &4]);
+    ///
+    /// list.splice(3, LinkedList::from_iter_back([10, 20]));
+    /// assert_eq!(list.length(), 8);
+    /// assert_eq!(Vec::from(list), vec![-1, 0, 1, 10, 20, 2, 3, 4]);
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            next: self.head.as_deref(),
+    pub fn splice(&mut self, index: usize, mut other: LinkedList<T>) {
+        assert!(
+            index <= self.length,
+            "splice index {index} out of bounds for length {}",
+            self.length
+        );
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        let rest = link.take();
+        *link = other.head.take();
+        while let Some(node) = link {
+            link = &mut node.next;
         }
+        *link = rest;
+        self.length += other.length;
     }
 
-    /// Creates a mutable iterator over the list's elements.
+    /// Groups runs of consecutive elements that share the same key into
+    /// sublists.
+    ///
+    /// Elements are cloned into the groups, which appear in list order.
+    /// Equal keys that are not adjacent start separate groups.
     ///
     /// # Examples
     ///
     /// ```
     /// use algo_rust::data_structs::linked_list::LinkedList;
-    /// let mut list = LinkedList::new();
-    /// list.push(1);
-    /// list.push(2);
+    /// let list = LinkedList::from_iter_back([1, 1, 2, 3, 3, 3]);
     ///
-    /// for elem in list.iter_mut() {
-    ///     *elem *= 2;
-    /// }
+    /// let groups = list.group_by(|&x| x);
+    /// assert_eq!(groups.length(), 3);
+    /// let sizes: Vec<usize> = groups.iter().map(|group| group.length()).collect();
+    /// assert_eq!(sizes, vec![2, 1, 3]);
+    /// let contents: Vec<Vec<i32>> = groups.into_iter().map(Vec::from).collect();
+    /// assert_eq!(contents, vec![vec![1, 1], vec![2], vec![3, 3, 3]]);
     ///
-    /// assert_eq!(list.pop(), Some(4));
-    /// assert_eq!(list.pop(), Some(2));
+    /// // Grouping by parity
+    /// let list = LinkedList::from_iter_back([2, 4, 1, 3, 6]);
+    /// assert_eq!(list.group_by(|x| x % 2).length(), 3);
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        IterMut {
-            next: self.head.as_deref_mut(),
+    pub fn group_by<K: PartialEq, F: FnMut(&T) -> K>(&self, mut key: F) -> LinkedList<LinkedList<T>>
+    where
+        T: Clone,
+    {
+        let mut groups = Vec::new();
+        let mut current: Option<(K, Vec<T>)> = None;
+        for value in self.iter() {
+            let value_key = key(value);
+            match &mut current {
+                Some((group_key, group)) if *group_key == value_key => {
+                    group.push(value.clone());
+                }
+                _ => {
+                    let next = (value_key, vec![value.clone()]);
+                    if let Some((_, group)) = current.replace(next) {
+                        groups.push(LinkedList::from(group));
+                    }
+                }
+            }
+        }
+        if let Some((_, group)) = current {
+            groups.push(LinkedList::from(group));
         }
+        LinkedList::from(groups)
     }
-}
 
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        let mut current = self.head.take();
-        while let Some(node) = current {
-            current = node.next;
-        }
+    /// Returns a new list with a clone of `sep` between each pair of
+    /// adjacent elements.
+    ///
+    /// Nothing is added before the first element or after the last, so
+    /// empty and single-element lists come back unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3]);
+    /// let spaced = list.intersperse(0);
+    /// assert_eq!(spaced.length(), 5);
+    /// assert_eq!(Vec::from(spaced), vec![1, 0, 2, 0, 3]);
+    ///
+    /// let single = LinkedList::from_iter_back([1]);
+    /// assert_eq!(single.intersperse(0), single);
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert!(empty.intersperse(0).is_empty());
+    /// ```
+    pub fn intersperse(&self, sep: T) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        let mut iter = self.iter();
+        let first = iter.next().cloned();
+        LinkedList::from_iter_back(
+            first
+                .into_iter()
+                .chain(iter.flat_map(|value| [sep.clone(), value.clone()])),
+        )
     }
-}
 
-impl<T: Clone> Clone for LinkedList<T> {
-    fn clone(&self) -> Self {
-        let mut new_list = Self::new();
-        let mut current = &self.head;
-        while let Some(node) = current {
-            new_list.push(node.value.clone());
-            current = &node.next;
+    /// Consumes both lists, combining positionally paired elements with `f`.
+    ///
+    /// The result stops at the end of the shorter list; the remaining
+    /// elements of the longer one are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let a = LinkedList::from_iter_back([1, 2, 3]);
+    /// let b = LinkedList::from_iter_back([10, 20, 30]);
+    /// assert_eq!(Vec::from(a.zip_with(b, |x, y| x + y)), vec![11, 22, 33]);
+    ///
+    /// // Unequal lengths
+    /// let a = LinkedList::from_iter_back([1, 2, 3, 4]);
+    /// let b = LinkedList::from_iter_back(["a", "b"]);
+    /// let zipped = a.zip_with(b, |n, s| s.repeat(n));
+    /// assert_eq!(zipped.length(), 2);
+    /// assert_eq!(Vec::from(zipped), vec!["a", "bb"]);
+    /// ```
+    pub fn zip_with<U, R, F: FnMut(T, U) -> R>(
+        self,
+        other: LinkedList<U>,
+        mut f: F,
+    ) -> LinkedList<R> {
+        LinkedList::from_iter_back(self.into_iter().zip(other).map(|(a, b)| f(a, b)))
+    }
+
+    /// Consumes both lists and alternates their elements, starting with
+    /// `self`.
+    ///
+    /// Once the shorter list runs out, the rest of the longer one follows
+    /// in order. Nodes are relinked rather than cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let a = LinkedList::from_iter_back([1, 3, 5]);
+    /// let b = LinkedList::from_iter_back([2, 4, 6]);
+    /// assert_eq!(Vec::from(a.interleave(b)), vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// // Different lengths
+    /// let a = LinkedList::from_iter_back([1, 2]);
+    /// let b = LinkedList::from_iter_back([10, 20, 30, 40]);
+    /// let mixed = a.interleave(b);
+    /// assert_eq!(mixed.length(), 6);
+    /// assert_eq!(Vec::from(mixed), vec![1, 10, 2, 20, 30, 40]);
+    ///
+    /// let a = LinkedList::from_iter_back([1, 2, 3]);
+    /// assert_eq!(Vec::from(a.interleave(LinkedList::new())), vec![1, 2, 3]);
+    /// ```
+    pub fn interleave(mut self, mut other: LinkedList<T>) -> LinkedList<T> {
+        let mut result = LinkedList::new();
+        result.length = self.length + other.length;
+        let mut current = self.head.take();
+        let mut waiting = other.head.take();
+        let mut tail = &mut result.head;
+        while let Some(mut node) = current {
+            current = node.next.take();
+            tail = &mut tail.insert(node).next;
+            std::mem::swap(&mut current, &mut waiting);
         }
-        new_list
+        *tail = waiting;
+        result
     }
-}
 
-/// An iterator that consumes the list.
-pub struct IntoIter<T> {
-    list: LinkedList<T>,
-}
+    /// Returns every pair `(a, b)` with `a` from `self` and `b` from
+    /// `other`, in row-major order.
+    ///
+    /// All pairs for the first element of `self` come first, in the order
+    /// of `other`, then those for the second element, and so on. The result
+    /// has `self.length() * other.length()` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let rows = LinkedList::from_iter_back([1, 2]);
+    /// let cols = LinkedList::from_iter_back(['a', 'b', 'c']);
+    /// let product = rows.cartesian_product(&cols);
+    /// assert_eq!(product.length(), 6);
+    /// assert_eq!(
+    ///     Vec::from(product),
+    ///     vec![(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'b'), (2, 'c')]
+    /// );
+    ///
+    /// let empty: LinkedList<char> = LinkedList::new();
+    /// assert!(rows.cartesian_product(&empty).is_empty());
+    /// ```
+    pub fn cartesian_product<U: Clone>(&self, other: &LinkedList<U>) -> LinkedList<(T, U)>
+    where
+        T: Clone,
+    {
+        LinkedList::from_iter_back(
+            self.iter()
+                .flat_map(|a| other.iter().map(move |b| (a.clone(), b.clone()))),
+        )
+    }
 
-/// An iterator over the list's elements.
-pub struct Iter<'a, T> {
-    next: Option<&'a Node<T>>,
-}
+    /// Compresses runs of consecutive equal elements into `(value, count)`
+    /// pairs.
+    ///
+    /// The inverse is [`run_length_decode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::{LinkedList, run_length_decode};
+    /// let list = LinkedList::from_iter_back(['a', 'a', 'a', 'b', 'c', 'c']);
+    ///
+    /// let encoded = list.run_length_encode();
+    /// let pairs: Vec<_> = encoded.iter().copied().collect();
+    /// assert_eq!(pairs, vec![('a', 3), ('b', 1), ('c', 2)]);
+    /// assert_eq!(run_length_decode(&encoded), list);
+    ///
+    /// let empty: LinkedList<char> = LinkedList::new();
+    /// assert!(empty.run_length_encode().is_empty());
+    /// ```
+    pub fn run_length_encode(&self) -> LinkedList<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        for value in self.iter() {
+            match runs.last_mut() {
+                Some((last, count)) if last == value => *count += 1,
+                _ => runs.push((value.clone(), 1)),
+            }
+        }
+        LinkedList::from(runs)
+    }
 
-/// A mutable iterator over the list's elements.
-pub struct IterMut<'a, T> {
-    next: Option<&'a mut Node<T>>,
-}
+    /// Consumes the list, applying `f` to each element and keeping the
+    /// `Some` results in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back(["1", "two", "3", "", "-4"]);
+    /// let numbers = list.filter_map(|s| s.parse::<i32>().ok());
+    /// assert_eq!(numbers.length(), 3);
+    /// assert_eq!(Vec::from(numbers), vec![1, 3, -4]);
+    ///
+    /// let list = LinkedList::from_iter_back([1, 2, 3]);
+    /// assert!(list.filter_map(|_| None::<i32>).is_empty());
+    /// ```
+    pub fn filter_map<U, F: FnMut(T) -> Option<U>>(self, f: F) -> LinkedList<U> {
+        LinkedList::from_iter_back(self.into_iter().filter_map(f))
+    }
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+    /// Consumes the list, keeping the leading run of elements that satisfy
+    /// `pred` and dropping everything from the first failure on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4, 1]);
+    /// let head = list.take_while(|&x| x < 3);
+    /// assert_eq!(head.length(), 2);
+    /// assert_eq!(Vec::from(head), vec![1, 2]);
+    ///
+    /// let list = LinkedList::from_iter_back([5, 1]);
+    /// assert!(list.take_while(|&x| x < 3).is_empty());
+    /// ```
+    pub fn take_while<F: FnMut(&T) -> bool>(mut self, mut pred: F) -> LinkedList<T> {
+        let run = self.iter().take_while(|value| pred(value)).count();
+        self.truncate(run);
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.list.head.take().map(|node| {
-            self.list.head = node.next;
-            node.value
-        })
+    /// Consumes the list, dropping the leading run of elements that satisfy
+    /// `pred` and keeping the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4, 1]);
+    /// let tail = list.skip_while(|&x| x < 3);
+    /// assert_eq!(tail.length(), 3);
+    /// assert_eq!(Vec::from(tail), vec![3, 4, 1]);
+    ///
+    /// let list = LinkedList::from_iter_back([1, 2]);
+    /// assert!(list.skip_while(|&x| x < 3).is_empty());
+    /// ```
+    pub fn skip_while<F: FnMut(&T) -> bool>(mut self, mut pred: F) -> LinkedList<T> {
+        while self.peek().is_some_and(&mut pred) {
+            self.pop();
+        }
+        self
     }
-}
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Consumes the list and splits it into sublists at every element that
+    /// satisfies `pred`, dropping those delimiters.
+    ///
+    /// This behaves like [`str::split`]: there is always one more sublist
+    /// than there are delimiters, so leading, trailing or adjacent
+    /// delimiters produce empty sublists, and an empty list gives a single
+    /// empty sublist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 0, 3, 4, 0, 5]);
+    /// let parts = list.split_when(|&x| x == 0);
+    /// assert_eq!(parts.length(), 3);
+    /// let parts: Vec<Vec<i32>> = parts.into_iter().map(Vec::from).collect();
+    /// assert_eq!(parts, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    ///
+    /// let list = LinkedList::from_iter_back([0, 1, 0, 0]);
+    /// let sizes: Vec<usize> = list
+    ///     .split_when(|&x| x == 0)
+    ///     .iter()
+    ///     .map(|part| part.length())
+    ///     .collect();
+    /// assert_eq!(sizes, vec![0, 1, 0, 0]);
+    /// ```
+    pub fn split_when<F: FnMut(&T) -> bool>(self, mut pred: F) -> LinkedList<LinkedList<T>> {
+        let mut parts = Vec::new();
+        let mut current = Vec::new();
+        for value in self {
+            if pred(&value) {
+                parts.push(LinkedList::from(std::mem::take(&mut current)));
+            } else {
+                current.push(value);
+            }
+        }
+        parts.push(LinkedList::from(current));
+        LinkedList::from(parts)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.map(|node| {
+    /// Applies `f` to every element in place, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, 2, 3]);
+    /// list.apply_in_place(|x| *x *= 2);
+    /// assert_eq!(list.length(), 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2, &4, &6]);
+    /// ```
+    pub fn apply_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Clears the list, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// assert_eq!(list.length(), 2);
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Removes every element for which `pred` returns `true` and returns the
+    /// number of elements removed.
+    ///
+    /// The predicate receives each element's original index along with a
+    /// reference to the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// for i in (0..6).rev() {
+    ///     list.push(i * 10);
+    /// }
+    ///
+    /// // Remove every element at an even index.
+    /// assert_eq!(list.remove_all(|index, _| index % 2 == 0), 3);
+    /// assert_eq!(list.length(), 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&10, &30, &50]);
+    ///
+    /// assert_eq!(list.remove_all(|_, &value| value > 100), 0);
+    /// assert_eq!(list.length(), 3);
+    /// ```
+    pub fn remove_all<F: FnMut(usize, &T) -> bool>(&mut self, mut pred: F) -> usize {
+        let mut removed = 0;
+        let mut index = 0;
+        let mut link = &mut self.head;
+        loop {
+            match link {
+                None => break,
+                Some(node) if pred(index, &node.value) => {
+                    *link = node.next.take();
+                    removed += 1;
+                }
+                Some(node) => link = &mut node.next,
+            }
+            index += 1;
+        }
+        self.length -= removed;
+        removed
+    }
+
+    /// Moves the first element satisfying `pred` to the head of the list.
+    ///
+    /// The matching node is unlinked and relinked at the front, so this is
+    /// the access step of a move-to-front self-organizing list. Returns
+    /// `false`, leaving the list unchanged, if no element matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, 2, 3, 4]);
+    ///
+    /// assert!(list.rotate_to_front(|&x| x == 3));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&3, &1, &2, &4]);
+    ///
+    /// // The head is already at the front
+    /// assert!(list.rotate_to_front(|&x| x == 3));
+    /// assert_eq!(list.peek(), Some(&3));
+    ///
+    /// assert!(!list.rotate_to_front(|&x| x == 9));
+    /// assert_eq!(list.length(), 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&3, &1, &2, &4]);
+    /// ```
+    pub fn rotate_to_front<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        let mut link = &mut self.head;
+        loop {
+            match link {
+                None => return false,
+                Some(node) if pred(&node.value) => break,
+                Some(node) => link = &mut node.next,
+            }
+        }
+        let mut node = link.take().unwrap();
+        *link = node.next.take();
+        node.next = self.head.take();
+        self.head = Some(node);
+        true
+    }
+
+    /// Removes duplicate elements anywhere in the list, keeping only the
+    /// first occurrence of each value.
+    ///
+    /// The values seen so far are tracked in a `HashSet`, so this runs in
+    /// O(n) overall.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, 2, 1, 3, 2, 4]);
+    /// list.dedup_all();
+    /// assert_eq!(list.length(), 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    pub fn dedup_all(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::new();
+        let duplicate: Vec<bool> = self.iter().map(|value| !seen.insert(value)).collect();
+        self.remove_all(|index, _| duplicate[index]);
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, 1, 2, 2, 2, 1, 3, 3]);
+    /// list.dedup();
+    /// assert_eq!(list.length(), 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &1, &3]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key, keeping the
+    /// first of each run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, -1, 2, -2, 2, 3, -1]);
+    /// list.dedup_by_key(|value: &i32| value.abs());
+    /// assert_eq!(list.length(), 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &-1]);
+    /// ```
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same` returns `true`.
+    ///
+    /// `same` is called as `same(element, kept)`, where `kept` is the
+    /// element that most recently survived. The relation does not need to
+    /// be transitive: each element is compared against the survivor, not
+    /// its immediate predecessor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::from_iter_back([1, 2, 3, 7, 8, 10]);
+    /// // 2 is within 1 of 1 and is removed; 3 is then compared against 1
+    /// list.dedup_by(|a: &i32, b: &i32| (a - b).abs() <= 1);
+    /// assert_eq!(list.length(), 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3, &7, &10]);
+    /// ```
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) {
+        let mut removed = 0;
+        let mut cursor = self.head.as_deref_mut();
+        while let Some(node) = cursor {
+            while let Some(next) = node.next.as_mut()
+                && same(&next.value, &node.value)
+            {
+                node.next = next.next.take();
+                removed += 1;
+            }
+            cursor = node.next.as_deref_mut();
+        }
+        self.length -= removed;
+    }
+
+    /// Returns the number of adjacent pairs that are out of ascending order.
+    ///
+    /// A pair `(a, b)` counts when `a > b`; equal neighbours do not. A
+    /// sorted list returns 0 and a strictly descending list of length `n`
+    /// returns `n - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// assert_eq!(LinkedList::from_iter_back([1, 2, 2, 3]).sortedness_violations(), 0);
+    /// assert_eq!(LinkedList::from_iter_back([5, 4, 3, 2, 1]).sortedness_violations(), 4);
+    /// assert_eq!(LinkedList::from_iter_back([1, 3, 2, 4, 0, 5]).sortedness_violations(), 2);
+    /// assert_eq!(LinkedList::<i32>::new().sortedness_violations(), 0);
+    /// ```
+    pub fn sortedness_violations(&self) -> usize
+    where
+        T: Ord,
+    {
+        self.iter_pairs().filter(|(a, b)| a > b).count()
+    }
+
+    /// Returns `true` if a sorted list contains an element equal to `target`.
+    ///
+    /// The list must be sorted in ascending order. Traversal stops at the
+    /// first element greater than `target`, so misses are usually cheaper
+    /// than a full scan. If the list is not sorted the result is unspecified:
+    /// an element that comes after a larger one may not be found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// for value in [9, 7, 5, 3, 1] {
+    ///     list.push(value);
+    /// }
+    ///
+    /// assert!(list.contains_sorted(&1));
+    /// assert!(list.contains_sorted(&5));
+    /// assert!(list.contains_sorted(&9));
+    /// assert!(!list.contains_sorted(&0));
+    /// assert!(!list.contains_sorted(&4));
+    /// assert!(!list.contains_sorted(&10));
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert!(!empty.contains_sorted(&1));
+    /// ```
+    ///
+    /// On an unsorted list the search may stop before reaching a match:
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// for value in [3, 5, 1] {
+    ///     list.push(value);
+    /// }
+    /// // The list is [1, 5, 3]; the search gives up at 5.
+    /// assert!(!list.contains_sorted(&3));
+    /// ```
+    pub fn contains_sorted(&self, target: &T) -> bool
+    where
+        T: Ord,
+    {
+        for value in self.iter() {
+            match value.cmp(target) {
+                Ordering::Less => continue,
+                Ordering::Equal => return true,
+                Ordering::Greater => return false,
+            }
+        }
+        false
+    }
+
+    /// Creates an iterator over the list's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Creates an iterator over the list's elements starting at `index`.
+    ///
+    /// The first `index` nodes are walked past up front. The iterator is
+    /// empty if `index` is at least the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(list.iter_from(2).collect::<Vec<_>>(), vec![&3, &4, &5]);
+    /// assert_eq!(list.iter_from(0).count(), 5);
+    /// assert_eq!(list.iter_from(5).next(), None);
+    /// assert_eq!(list.iter_from(usize::MAX).next(), None);
+    /// ```
+    pub fn iter_from(&self, index: usize) -> Iter<'_, T> {
+        let mut next = self.head.as_deref();
+        for _ in 0..index.min(self.length) {
+            next = next.and_then(|node| node.next.as_deref());
+        }
+        Iter { next }
+    }
+
+    /// Creates an iterator over every `step`-th element, starting with the
+    /// head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(list.iter_step(2).collect::<Vec<_>>(), vec![&1, &3, &5]);
+    /// assert_eq!(list.iter_step(3).collect::<Vec<_>>(), vec![&1, &4]);
+    /// assert_eq!(list.iter_step(1).count(), 5);
+    /// assert_eq!(list.iter_step(10).collect::<Vec<_>>(), vec![&1]);
+    /// ```
+    pub fn iter_step(&self, step: usize) -> Step<'_, T> {
+        assert!(step > 0, "step must be positive");
+        Step {
+            next: self.head.as_deref(),
+            step,
+        }
+    }
+
+    /// Creates an iterator over each pair of adjacent elements, in order.
+    ///
+    /// Lists with fewer than two elements yield no pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3]);
+    /// assert_eq!(list.iter_pairs().collect::<Vec<_>>(), vec![(&1, &2), (&2, &3)]);
+    ///
+    /// // Differences between neighbours
+    /// let list = LinkedList::from_iter_back([1, 4, 9, 16]);
+    /// let diffs: Vec<i32> = list.iter_pairs().map(|(a, b)| b - a).collect();
+    /// assert_eq!(diffs, vec![3, 5, 7]);
+    ///
+    /// let single = LinkedList::from_iter_back([1]);
+    /// assert_eq!(single.iter_pairs().next(), None);
+    /// ```
+    pub fn iter_pairs(&self) -> Pairs<'_, T> {
+        Pairs {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Returns a reference to the `n`-th element counting from the back, where
+    /// `n == 0` is the last element.
+    ///
+    /// Returns `None` if `n` is at least the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([10, 20, 30, 40]);
+    ///
+    /// assert_eq!(list.iter_nth_back(0), Some(&40));
+    /// assert_eq!(list.iter_nth_back(3), Some(&10));
+    /// assert_eq!(list.iter_nth_back(4), None);
+    ///
+    /// // Back access mirrors forward access from the other end
+    /// for n in 0..list.length() {
+    ///     assert_eq!(list.iter_nth_back(n), list.iter().nth(list.length() - 1 - n));
+    /// }
+    /// ```
+    pub fn iter_nth_back(&self, n: usize) -> Option<&T> {
+        let index = self.length.checked_sub(n)?.checked_sub(1)?;
+        self.iter().nth(index)
+    }
+
+    /// Returns a reference to the element at `index`, counting from the
+    /// head.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] holding the index and the list's length if
+    /// `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::{IndexError, LinkedList};
+    /// let list = LinkedList::from_iter_back([10, 20, 30]);
+    /// assert_eq!(list.try_get(1), Ok(&20));
+    ///
+    /// let err = list.try_get(5).unwrap_err();
+    /// assert_eq!(err, IndexError { index: 5, length: 3 });
+    /// assert_eq!(err.to_string(), "index 5 out of bounds for length 3");
+    /// ```
+    pub fn try_get(&self, index: usize) -> Result<&T, IndexError> {
+        self.iter().nth(index).ok_or(IndexError {
+            index,
+            length: self.length,
+        })
+    }
+
+    /// Creates a mutable iterator over the list's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    ///
+    /// for elem in list.iter_mut() {
+    ///     *elem *= 2;
+    /// }
+    ///
+    /// assert_eq!(list.pop(), Some(4));
+    /// assert_eq!(list.pop(), Some(2));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+        }
+    }
+}
+
+/// An error returned by [`LinkedList::try_get`] for an out-of-bounds index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexError {
+    /// The index that was requested.
+    pub index: usize,
+    /// The length of the list at the time of the request.
+    pub length: usize,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} out of bounds for length {}",
+            self.index, self.length
+        )
+    }
+}
+
+impl Error for IndexError {}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates an iterator that consumes the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            list: self,
+            back: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    /// Creates a list holding the vector's elements in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from(vec![1, 2, 3]);
+    /// assert_eq!(list.length(), 3);
+    /// assert_eq!(list.peek(), Some(&1));
+    ///
+    /// let back: Vec<i32> = list.into();
+    /// assert_eq!(back, vec![1, 2, 3]);
+    /// ```
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_iter_back(vec)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    /// Creates a list holding the array's elements in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.length(), 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter_back(array)
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    /// Collects the list's elements into a vector, front first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let mut list = LinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// assert_eq!(Vec::from(list), vec![2, 1]);
+    /// ```
+    fn from(list: LinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.length);
+        vec.extend(list);
+        vec
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    /// Returns `true` if both lists hold equal elements in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let a = LinkedList::from_iter_back([1, 2, 3]);
+    /// let b = LinkedList::from_iter_back([1, 2, 3]);
+    /// let c = LinkedList::from_iter_back([1, 2]);
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, c);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    /// Compares two lists lexicographically, element by element.
+    ///
+    /// A list that is a prefix of a longer list compares as less.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let a = LinkedList::from_iter_back([1, 2]);
+    /// let b = LinkedList::from_iter_back([1, 3]);
+    /// let c = LinkedList::from_iter_back([1]);
+    /// assert!(a < b);
+    /// assert!(c < a);
+    /// assert_eq!(a.cmp(&LinkedList::from_iter_back([1, 2])), Ordering::Equal);
+    ///
+    /// let mut lists = vec![b, a, c];
+    /// lists.sort();
+    /// assert_eq!(lists[0].length(), 1);
+    /// assert_eq!(lists[2].peek(), Some(&1));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            current = node.next;
+        }
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    /// Returns a copy of the list with its elements in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3]);
+    /// let copy = list.clone();
+    /// assert!(copy == list);
+    /// assert_eq!(copy.cmp(&list), Ordering::Equal);
+    /// assert_eq!(Vec::from(copy), vec![1, 2, 3]);
+    /// ```
+    fn clone(&self) -> Self {
+        Self::from_iter_back(self.iter().cloned())
+    }
+}
+
+/// Concatenates a list of lists into a single list.
+///
+/// The inner lists are joined in order and their nodes are relinked rather
+/// than cloned.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::data_structs::linked_list::{LinkedList, flatten};
+/// let mut a = LinkedList::new();
+/// a.push(2);
+/// a.push(1);
+/// let mut b = LinkedList::new();
+/// b.push(3);
+/// let mut c = LinkedList::new();
+/// c.push(5);
+/// c.push(4);
+///
+/// let mut lists = LinkedList::new();
+/// lists.push(c);
+/// lists.push(b);
+/// lists.push(a);
+///
+/// let flat = flatten(lists);
+/// assert_eq!(flat.length(), 5);
+/// assert_eq!(flat.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+/// ```
+///
+/// Empty inner lists contribute nothing:
+///
+/// ```
+/// use algo_rust::data_structs::linked_list::{LinkedList, flatten};
+/// let mut a = LinkedList::new();
+/// a.push(1);
+///
+/// let mut lists = LinkedList::new();
+/// lists.push(LinkedList::new());
+/// lists.push(a);
+/// lists.push(LinkedList::new());
+///
+/// let flat = flatten(lists);
+/// assert_eq!(flat.length(), 1);
+/// assert_eq!(flat.peek(), Some(&1));
+///
+/// let empty: LinkedList<LinkedList<i32>> = LinkedList::new();
+/// assert!(flatten(empty).is_empty());
+/// ```
+pub fn flatten<U>(list: LinkedList<LinkedList<U>>) -> LinkedList<U> {
+    let mut flat = LinkedList::new();
+    let mut tail = &mut flat.head;
+    for mut inner in list {
+        flat.length += inner.length;
+        *tail = inner.head.take();
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+    }
+    flat
+}
+
+/// Expands `(value, count)` pairs into a list repeating each value `count`
+/// times.
+///
+/// This is the inverse of [`LinkedList::run_length_encode`]. Pairs with a
+/// count of zero contribute nothing.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::data_structs::linked_list::{LinkedList, run_length_decode};
+/// let encoded = LinkedList::from_iter_back([(1, 2), (0, 0), (5, 3)]);
+/// let decoded = run_length_decode(&encoded);
+/// assert_eq!(decoded.length(), 5);
+/// assert_eq!(Vec::from(decoded), vec![1, 1, 5, 5, 5]);
+/// ```
+pub fn run_length_decode<T: Clone>(encoded: &LinkedList<(T, usize)>) -> LinkedList<T> {
+    LinkedList::from_iter_back(
+        encoded
+            .iter()
+            .flat_map(|(value, count)| std::iter::repeat_n(value.clone(), *count)),
+    )
+}
+
+/// An iterator that consumes the list.
+///
+/// Iterating from the back moves the remaining elements into a buffer the
+/// first time, since the list itself can only be walked forwards.
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+    back: VecDeque<T>,
+}
+
+/// An iterator over the list's elements.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+/// An iterator over every `step`-th element of the list.
+pub struct Step<'a, T> {
+    next: Option<&'a Node<T>>,
+    step: usize,
+}
+
+/// An iterator over pairs of adjacent elements of the list.
+pub struct Pairs<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+/// A mutable iterator over the list's elements.
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop().or_else(|| self.back.pop_front())
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    /// Removes and returns the element at the back of the remaining list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::linked_list::LinkedList;
+    /// let list = LinkedList::from_iter_back([1, 2, 3, 4]);
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next_back(), Some(4));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next_back(), None);
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let forward: Vec<i32> = LinkedList::from_iter_back(1..=5).into_iter().collect();
+    /// let mut backward: Vec<i32> = LinkedList::from_iter_back(1..=5).into_iter().rev().collect();
+    /// backward.reverse();
+    /// assert_eq!(forward, backward);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(value) = self.list.pop() {
+            self.back.push_back(value);
+        }
+        self.back.pop_back()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
             self.next = node.next.as_deref();
             &node.value
         })
     }
 }
 
+impl<'a, T> Iterator for Step<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        let mut next = Some(node);
+        for _ in 0..self.step {
+            next = next.and_then(|node| node.next.as_deref());
+        }
+        self.next = next;
+        Some(&node.value)
+    }
+}
+
+impl<'a, T> Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.next?;
+        let second = first.next.as_deref()?;
+        self.next = Some(second);
+        Some((&first.value, &second.value))
+    }
+}
+
 impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
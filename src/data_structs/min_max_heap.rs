@@ -0,0 +1,276 @@
+use std::cmp::Ordering;
+
+/// A double-ended priority queue using the min-max heap layout.
+///
+/// Levels of the implicit binary tree alternate between min levels and max
+/// levels, starting with a min level at the root. Every element on a min
+/// level is no greater than its descendants and every element on a max
+/// level is no less, so the minimum sits at the root and the maximum is one
+/// of its children. Both ends can be peeked in O(1) and popped in
+/// O(log n).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+///
+/// let mut heap = MinMaxHeap::new();
+/// for value in [5, 1, 9, 3, 7, 2, 8] {
+///     heap.push(value);
+/// }
+/// assert_eq!(heap.peek_min(), Some(&1));
+/// assert_eq!(heap.peek_max(), Some(&9));
+///
+/// // Interleaved pops always see the current extremes
+/// assert_eq!(heap.pop_min(), Some(1));
+/// assert_eq!(heap.pop_max(), Some(9));
+/// assert_eq!(heap.pop_min(), Some(2));
+/// assert_eq!(heap.pop_max(), Some(8));
+/// assert_eq!(heap.pop_max(), Some(7));
+/// assert_eq!(heap.pop_min(), Some(3));
+/// assert_eq!(heap.pop_min(), Some(5));
+/// assert_eq!(heap.pop_max(), None);
+///
+/// // Duplicates come out at both ends
+/// for value in [4, 4, 1, 9, 9, 1] {
+///     heap.push(value);
+/// }
+/// assert_eq!(heap.pop_max(), Some(9));
+/// assert_eq!(heap.pop_max(), Some(9));
+/// assert_eq!(heap.pop_min(), Some(1));
+/// assert_eq!(heap.peek_min(), Some(&1));
+/// assert_eq!(heap.peek_max(), Some(&4));
+/// assert_eq!(heap.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinMaxHeap<T: Ord> {
+    data: Vec<T>,
+}
+
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> MinMaxHeap<T> {
+    /// Creates an empty heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let heap: MinMaxHeap<i32> = MinMaxHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        MinMaxHeap { data: Vec::new() }
+    }
+
+    /// Adds a value to the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.push(2);
+    /// heap.push(4);
+    /// assert_eq!(heap.peek_min(), Some(&2));
+    /// assert_eq!(heap.peek_max(), Some(&4));
+    /// ```
+    pub fn push(&mut self, value: T) {
+        let index = self.data.len();
+        self.data.push(value);
+        bubble_up(&mut self.data, index);
+    }
+
+    /// Returns the smallest value without removing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// assert_eq!(heap.peek_min(), None);
+    /// heap.push(3);
+    /// heap.push(1);
+    /// assert_eq!(heap.peek_min(), Some(&1));
+    /// ```
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the largest value without removing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// assert_eq!(heap.peek_max(), None);
+    /// heap.push(1);
+    /// heap.push(3);
+    /// assert_eq!(heap.peek_max(), Some(&3));
+    /// ```
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|index| &self.data[index])
+    }
+
+    /// Removes and returns the smallest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.push(3);
+    /// heap.push(1);
+    /// assert_eq!(heap.pop_min(), Some(1));
+    /// assert_eq!(heap.pop_min(), Some(3));
+    /// assert_eq!(heap.pop_min(), None);
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.remove_at(0)
+    }
+
+    /// Removes and returns the largest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.push(1);
+    /// heap.push(3);
+    /// assert_eq!(heap.pop_max(), Some(3));
+    /// assert_eq!(heap.pop_max(), Some(1));
+    /// assert_eq!(heap.pop_max(), None);
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        let index = self.max_index()?;
+        self.remove_at(index)
+    }
+
+    /// Returns the number of values in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.push(1);
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the heap holds no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::min_max_heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// assert!(heap.is_empty());
+    /// heap.push(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn max_index(&self) -> Option<usize> {
+        match self.data.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ if self.data[2] > self.data[1] => Some(2),
+            _ => Some(1),
+        }
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.data.len() {
+            return None;
+        }
+        let value = self.data.swap_remove(index);
+        if index < self.data.len() {
+            trickle_down(&mut self.data, index);
+        }
+        Some(value)
+    }
+}
+
+/// Returns `true` if `index` lies on a min level (even depth).
+fn is_min_level(index: usize) -> bool {
+    (index + 1).ilog2().is_multiple_of(2)
+}
+
+/// The ordering an element must have against its same-kind ancestors:
+/// `Less` on min levels and `Greater` on max levels.
+fn level_order(index: usize) -> Ordering {
+    if is_min_level(index) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+fn bubble_up<T: Ord>(data: &mut [T], mut index: usize) {
+    if index == 0 {
+        return;
+    }
+    let parent = (index - 1) / 2;
+    let mut order = level_order(index);
+    if data[index].cmp(&data[parent]) == order.reverse() {
+        data.swap(index, parent);
+        index = parent;
+        order = order.reverse();
+    }
+    while index >= 3 {
+        let grandparent = ((index - 1) / 2 - 1) / 2;
+        if data[index].cmp(&data[grandparent]) != order {
+            break;
+        }
+        data.swap(index, grandparent);
+        index = grandparent;
+    }
+}
+
+fn trickle_down<T: Ord>(data: &mut [T], mut index: usize) {
+    let order = level_order(index);
+    loop {
+        // The best of the children and grandchildren, in level order
+        let first_child = 2 * index + 1;
+        let first_grandchild = 4 * index + 3;
+        let Some(best) = (first_child..first_child + 2)
+            .chain(first_grandchild..first_grandchild + 4)
+            .filter(|&i| i < data.len())
+            .reduce(|best, i| {
+                if data[i].cmp(&data[best]) == order {
+                    i
+                } else {
+                    best
+                }
+            })
+        else {
+            return;
+        };
+        if data[best].cmp(&data[index]) != order {
+            return;
+        }
+        data.swap(best, index);
+        if best < first_grandchild {
+            return;
+        }
+        let parent = (best - 1) / 2;
+        if data[best].cmp(&data[parent]) == order.reverse() {
+            data.swap(best, parent);
+        }
+        index = best;
+    }
+}
@@ -1 +1,25 @@
+pub mod bitset;
+pub mod circular;
+pub mod concurrent;
+pub mod counter;
+pub mod difference;
+pub mod expiring;
+pub mod interval_set;
 pub mod linked_list;
+pub mod min_max_heap;
+pub mod move_to_front;
+pub mod nary_tree;
+pub mod os_tree;
+pub mod pairing_heap;
+pub mod persistent;
+pub mod polynomial;
+pub mod quadtree;
+pub mod rope;
+pub mod sparse_table;
+pub mod string_builder;
+pub mod timing_wheel;
+pub mod treap;
+pub mod tst;
+pub mod two_three;
+pub mod veb;
+pub mod wavelet;
@@ -0,0 +1,186 @@
+use crate::data_structs::linked_list::{Iter, LinkedList};
+
+/// A self-organizing list that moves each element it finds to the front.
+///
+/// Every successful [`lookup`](Self::lookup) relinks the found node at the
+/// head with [`LinkedList::rotate_to_front`], so frequently accessed values
+/// drift towards the front and later lookups of them stop early. The list
+/// counts lookups and element comparisons so the effect can be measured.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+///
+/// let mut list = MoveToFrontList::new();
+/// for value in 1..=10 {
+///     list.insert(value);
+/// }
+/// // The list is [10, 9, ..., 1]; finding 1 scans all of it
+/// assert!(list.lookup(&1));
+/// assert_eq!(list.comparisons(), 10);
+///
+/// // 1 is now at the head, so repeated lookups are cheap
+/// assert_eq!(list.iter().next(), Some(&1));
+/// for _ in 0..5 {
+///     assert!(list.lookup(&1));
+/// }
+/// assert_eq!(list.comparisons(), 15);
+/// assert_eq!(list.lookups(), 6);
+///
+/// // A miss scans everything and changes nothing
+/// assert!(!list.lookup(&42));
+/// assert_eq!(list.comparisons(), 25);
+/// assert_eq!(list.iter().next(), Some(&1));
+/// ```
+#[derive(Debug)]
+pub struct MoveToFrontList<T: PartialEq> {
+    list: LinkedList<T>,
+    lookups: usize,
+    comparisons: usize,
+}
+
+impl<T: PartialEq> MoveToFrontList<T> {
+    /// Creates an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let list: MoveToFrontList<i32> = MoveToFrontList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        MoveToFrontList {
+            list: LinkedList::new(),
+            lookups: 0,
+            comparisons: 0,
+        }
+    }
+
+    /// Adds a value at the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        self.list.push(value);
+    }
+
+    /// Searches for `value`, moving it to the front if found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// for value in ["a", "b", "c"] {
+    ///     list.insert(value);
+    /// }
+    /// assert!(list.lookup(&"b"));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&"b", &"c", &"a"]);
+    /// assert!(!list.lookup(&"z"));
+    /// ```
+    pub fn lookup(&mut self, value: &T) -> bool {
+        self.lookups += 1;
+        let comparisons = &mut self.comparisons;
+        self.list.rotate_to_front(|candidate| {
+            *comparisons += 1;
+            candidate == value
+        })
+    }
+
+    /// Returns the number of lookups performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// list.insert(1);
+    /// list.lookup(&1);
+    /// list.lookup(&2);
+    /// assert_eq!(list.lookups(), 2);
+    /// ```
+    pub fn lookups(&self) -> usize {
+        self.lookups
+    }
+
+    /// Returns the total number of element comparisons made by lookups.
+    ///
+    /// A lookup that finds the value at position `i` makes `i + 1`
+    /// comparisons; a miss compares against every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// for value in [3, 2, 1] {
+    ///     list.insert(value);
+    /// }
+    /// list.lookup(&2);
+    /// assert_eq!(list.comparisons(), 2);
+    /// ```
+    pub fn comparisons(&self) -> usize {
+        self.comparisons
+    }
+
+    /// Returns the number of values in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// list.insert(1);
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.list.length()
+    }
+
+    /// Returns `true` if the list holds no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// assert!(list.is_empty());
+    /// list.insert(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Creates an iterator over the values from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::move_to_front::MoveToFrontList;
+    /// let mut list = MoveToFrontList::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// assert_eq!(list.iter().count(), 2);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+}
+
+impl<T: PartialEq> Default for MoveToFrontList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
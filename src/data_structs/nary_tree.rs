@@ -0,0 +1,224 @@
+use std::collections::VecDeque;
+
+/// A tree in which every node holds a value and any number of children.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::nary_tree::Tree;
+///
+/// //        1
+/// //      / | \
+/// //     2  3  4
+/// //    / \    |
+/// //   5   6   7
+/// //           |
+/// //           8
+/// let mut root = Tree::new(1);
+/// let two = root.add_child(Tree::new(2));
+/// two.add_child(Tree::new(5));
+/// two.add_child(Tree::new(6));
+/// root.add_child(Tree::new(3));
+/// root.add_child(Tree::new(4))
+///     .add_child(Tree::new(7))
+///     .add_child(Tree::new(8));
+///
+/// assert_eq!(root.count_nodes(), 8);
+/// assert_eq!(root.depth(), 4);
+/// assert_eq!(
+///     root.pre_order().collect::<Vec<_>>(),
+///     vec![&1, &2, &5, &6, &3, &4, &7, &8]
+/// );
+/// assert_eq!(
+///     root.bfs().collect::<Vec<_>>(),
+///     vec![&1, &2, &3, &4, &5, &6, &7, &8]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+impl<T> Tree<T> {
+    /// Creates a tree holding a single node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let tree = Tree::new("root");
+    /// assert_eq!(tree.value(), &"root");
+    /// assert!(tree.children().is_empty());
+    /// ```
+    pub fn new(value: T) -> Self {
+        Tree {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the value at the root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let tree = Tree::new(1);
+    /// assert_eq!(tree.value(), &1);
+    /// ```
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value at the root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// *tree.value_mut() = 2;
+    /// assert_eq!(tree.value(), &2);
+    /// ```
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Returns the root's child subtrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// tree.add_child(Tree::new(2));
+    /// assert_eq!(tree.children()[0].value(), &2);
+    /// ```
+    pub fn children(&self) -> &[Tree<T>] {
+        &self.children
+    }
+
+    /// Appends `child` as the last child of the root and returns a mutable
+    /// reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// tree.add_child(Tree::new(2)).add_child(Tree::new(3));
+    /// assert_eq!(tree.children().len(), 1);
+    /// assert_eq!(tree.depth(), 3);
+    /// ```
+    pub fn add_child(&mut self, child: Tree<T>) -> &mut Tree<T> {
+        self.children.push(child);
+        self.children.last_mut().unwrap()
+    }
+
+    /// Returns the number of nodes on the longest path from the root to a
+    /// leaf. A single node has depth 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// assert_eq!(tree.depth(), 1);
+    /// tree.add_child(Tree::new(2));
+    /// tree.add_child(Tree::new(3)).add_child(Tree::new(4));
+    /// assert_eq!(tree.depth(), 3);
+    /// ```
+    pub fn depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((node, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            stack.extend(node.children.iter().map(|child| (child, depth + 1)));
+        }
+        max_depth
+    }
+
+    /// Returns the total number of nodes in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// tree.add_child(Tree::new(2));
+    /// tree.add_child(Tree::new(3)).add_child(Tree::new(4));
+    /// assert_eq!(tree.count_nodes(), 4);
+    /// ```
+    pub fn count_nodes(&self) -> usize {
+        self.pre_order().count()
+    }
+
+    /// Creates an iterator that visits each node before its children, and
+    /// children from first to last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// tree.add_child(Tree::new(2)).add_child(Tree::new(3));
+    /// tree.add_child(Tree::new(4));
+    /// assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// ```
+    pub fn pre_order(&self) -> PreOrder<'_, T> {
+        PreOrder { stack: vec![self] }
+    }
+
+    /// Creates an iterator that visits nodes level by level, from the root
+    /// downwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::nary_tree::Tree;
+    /// let mut tree = Tree::new(1);
+    /// tree.add_child(Tree::new(2)).add_child(Tree::new(3));
+    /// tree.add_child(Tree::new(4));
+    /// assert_eq!(tree.bfs().collect::<Vec<_>>(), vec![&1, &2, &4, &3]);
+    /// ```
+    pub fn bfs(&self) -> Bfs<'_, T> {
+        Bfs {
+            queue: VecDeque::from([self]),
+        }
+    }
+}
+
+/// A pre-order iterator over a tree's values.
+pub struct PreOrder<'a, T> {
+    stack: Vec<&'a Tree<T>>,
+}
+
+/// A breadth-first iterator over a tree's values.
+pub struct Bfs<'a, T> {
+    queue: VecDeque<&'a Tree<T>>,
+}
+
+impl<'a, T> Iterator for PreOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stack.pop().map(|node| {
+            self.stack.extend(node.children.iter().rev());
+            &node.value
+        })
+    }
+}
+
+impl<'a, T> Iterator for Bfs<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.queue.pop_front().map(|node| {
+            self.queue.extend(node.children.iter());
+            &node.value
+        })
+    }
+}
@@ -0,0 +1,419 @@
+use std::cmp::Ordering;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    height: usize,
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(value: T) -> Node<T> {
+        Node {
+            value,
+            height: 1,
+            size: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+/// An AVL tree augmented with subtree sizes for order statistics.
+///
+/// Every node records the height and size of its subtree. Heights keep the
+/// tree balanced, so its depth stays O(log n); sizes let
+/// [`rank`](Self::rank) and [`select`](Self::select) skip whole subtrees,
+/// so both run in O(log n). Values are stored without duplicates.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+///
+/// let mut tree = OrderStatisticTree::new();
+/// for value in [50, 20, 80, 10, 30, 70, 90] {
+///     tree.insert(value);
+/// }
+///
+/// // rank counts the smaller values; select finds the k-th smallest
+/// assert_eq!(tree.rank(&50), 3);
+/// assert_eq!(tree.rank(&55), 4);
+/// assert_eq!(tree.select(0), Some(&10));
+/// assert_eq!(tree.select(6), Some(&90));
+/// assert_eq!(tree.select(7), None);
+///
+/// assert!(tree.remove(&20));
+/// assert_eq!(tree.rank(&50), 2);
+/// assert_eq!(tree.select(1), Some(&30));
+/// ```
+///
+/// Sorted insertions stay balanced, and removing the root keeps ranks
+/// consistent:
+///
+/// ```
+/// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+///
+/// let mut tree = OrderStatisticTree::new();
+/// for value in 1..=7 {
+///     assert!(tree.insert(value));
+/// }
+/// assert!(!tree.insert(4));
+/// assert!(tree.remove(&4));
+/// assert!(!tree.remove(&4));
+/// assert_eq!(tree.len(), 6);
+/// for (k, value) in [1, 2, 3, 5, 6, 7].iter().enumerate() {
+///     assert_eq!(tree.select(k), Some(value));
+///     assert_eq!(tree.rank(value), k);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OrderStatisticTree<T> {
+    root: Link<T>,
+}
+
+impl<T: Ord> OrderStatisticTree<T> {
+    /// Creates an empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let tree: OrderStatisticTree<i32> = OrderStatisticTree::new();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        OrderStatisticTree { root: None }
+    }
+
+    /// Inserts a value, returning `false` if it was already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// assert!(tree.insert(1));
+    /// assert!(!tree.insert(1));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        insert(&mut self.root, value)
+    }
+
+    /// Removes a value, returning `true` if it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// tree.insert(1);
+    /// assert!(tree.remove(&1));
+    /// assert!(!tree.remove(&1));
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        remove(&mut self.root, value)
+    }
+
+    /// Returns `true` if the tree contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// tree.insert(4);
+    /// assert!(tree.contains(&4));
+    /// assert!(!tree.contains(&5));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node = self.root.as_deref();
+        while let Some(current) = node {
+            node = match value.cmp(&current.value) {
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Greater => current.right.as_deref(),
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Returns the number of values in the tree that are less than `value`.
+    ///
+    /// `value` does not need to be in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// for value in [10, 20, 30] {
+    ///     tree.insert(value);
+    /// }
+    /// assert_eq!(tree.rank(&5), 0);
+    /// assert_eq!(tree.rank(&20), 1);
+    /// assert_eq!(tree.rank(&25), 2);
+    /// assert_eq!(tree.rank(&99), 3);
+    /// ```
+    pub fn rank(&self, value: &T) -> usize {
+        let mut rank = 0;
+        let mut node = self.root.as_deref();
+        while let Some(current) = node {
+            if *value <= current.value {
+                node = current.left.as_deref();
+            } else {
+                rank += size(&current.left) + 1;
+                node = current.right.as_deref();
+            }
+        }
+        rank
+    }
+
+    /// Returns the `k`-th smallest value, counting from zero, or `None` if
+    /// `k` is at least the length of the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// for value in [3, 1, 2] {
+    ///     tree.insert(value);
+    /// }
+    /// assert_eq!(tree.select(0), Some(&1));
+    /// assert_eq!(tree.select(2), Some(&3));
+    /// assert_eq!(tree.select(3), None);
+    /// ```
+    pub fn select(&self, mut k: usize) -> Option<&T> {
+        let mut node = self.root.as_deref();
+        while let Some(current) = node {
+            let left = size(&current.left);
+            node = match k.cmp(&left) {
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Equal => return Some(&current.value),
+                Ordering::Greater => {
+                    k -= left + 1;
+                    current.right.as_deref()
+                }
+            };
+        }
+        None
+    }
+
+    /// Returns the number of values in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns `true` if the tree holds no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// assert!(tree.is_empty());
+    /// tree.insert(1);
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the height of the tree; an empty tree has height 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// for value in 0..1000 {
+    ///     tree.insert(value);
+    /// }
+    /// // An AVL tree is at most about 1.44 * log2(n) tall
+    /// assert!(tree.height() <= 14);
+    /// ```
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Creates an in-order iterator over the tree's values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::os_tree::OrderStatisticTree;
+    /// let mut tree = OrderStatisticTree::new();
+    /// for value in [2, 3, 1] {
+    ///     tree.insert(value);
+    /// }
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+
+impl<T: Ord> Default for OrderStatisticTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn insert<T: Ord>(link: &mut Link<T>, value: T) -> bool {
+    let Some(node) = link else {
+        *link = Some(Box::new(Node::new(value)));
+        return true;
+    };
+    let inserted = match value.cmp(&node.value) {
+        Ordering::Equal => return false,
+        Ordering::Less => insert(&mut node.left, value),
+        Ordering::Greater => insert(&mut node.right, value),
+    };
+    rebalance(link);
+    inserted
+}
+
+fn remove<T: Ord>(link: &mut Link<T>, value: &T) -> bool {
+    let Some(node) = link else {
+        return false;
+    };
+    let removed = match value.cmp(&node.value) {
+        Ordering::Less => remove(&mut node.left, value),
+        Ordering::Greater => remove(&mut node.right, value),
+        Ordering::Equal => {
+            match (node.left.is_some(), node.right.is_some()) {
+                (false, _) => *link = node.right.take(),
+                (_, false) => *link = node.left.take(),
+                (true, true) => node.value = remove_min(&mut node.right),
+            }
+            true
+        }
+    };
+    rebalance(link);
+    removed
+}
+
+/// Removes and returns the smallest value of a non-empty subtree.
+fn remove_min<T>(link: &mut Link<T>) -> T {
+    let node = link.as_mut().unwrap();
+    if node.left.is_some() {
+        let value = remove_min(&mut node.left);
+        rebalance(link);
+        value
+    } else {
+        let mut node = link.take().unwrap();
+        *link = node.right.take();
+        node.value
+    }
+}
+
+/// Restores the AVL invariant at `link` after one of its subtrees changed
+/// height by at most one, and refreshes the node's height and size.
+fn rebalance<T>(link: &mut Link<T>) {
+    let Some(node) = link else {
+        return;
+    };
+    node.update();
+    let balance = node.balance_factor();
+    if balance > 1 {
+        if node
+            .left
+            .as_ref()
+            .is_some_and(|left| left.balance_factor() < 0)
+        {
+            rotate_left(&mut node.left);
+        }
+        rotate_right(link);
+    } else if balance < -1 {
+        if node
+            .right
+            .as_ref()
+            .is_some_and(|right| right.balance_factor() > 0)
+        {
+            rotate_right(&mut node.right);
+        }
+        rotate_left(link);
+    }
+}
+
+fn rotate_right<T>(link: &mut Link<T>) {
+    let mut root = link.take().unwrap();
+    let mut left = root.left.take().unwrap();
+    root.left = left.right.take();
+    root.update();
+    left.right = Some(root);
+    left.update();
+    *link = Some(left);
+}
+
+fn rotate_left<T>(link: &mut Link<T>) {
+    let mut root = link.take().unwrap();
+    let mut right = root.right.take().unwrap();
+    root.right = right.left.take();
+    root.update();
+    right.left = Some(root);
+    right.update();
+    *link = Some(right);
+}
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+/// An in-order iterator over an order-statistics tree's values.
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stack.pop().map(|node| {
+            self.push_left(node.right.as_deref());
+            &node.value
+        })
+    }
+}
@@ -0,0 +1,413 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The source of heap ids, so that a handle can name the heap it came from.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    /// The leftmost child.
+    child: Option<usize>,
+    /// The next sibling to the right.
+    next: Option<usize>,
+    /// The left sibling, or the parent for a leftmost child.
+    prev: Option<usize>,
+}
+
+#[derive(Debug)]
+struct Slot<T> {
+    /// Bumped each time the slot's node is removed, so that handles to it
+    /// can be told apart from handles to a later node in the same slot.
+    generation: u64,
+    node: Option<Node<T>>,
+}
+
+/// A handle to a value in a [`PairingHeap`], used to decrease its key.
+///
+/// A handle stays valid until its value is removed by
+/// [`delete_min`](PairingHeap::delete_min) or its heap is merged into
+/// another. Using it after that panics, even if the slot has since been
+/// reused by a new value, and so does using it with a different heap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    heap: u64,
+    index: usize,
+    generation: u64,
+}
+
+/// A min-heap stored as a multiway tree, with cheap merging and
+/// decrease-key.
+///
+/// Inserting, merging and decreasing a key each link two trees in O(1):
+/// the root with the larger value becomes the leftmost child of the other.
+/// [`delete_min`](Self::delete_min) removes the root and rebuilds the tree
+/// from its children with the two-pass pairing scheme, which takes
+/// O(log n) amortized time. Nodes live in a `Vec` and refer to each other
+/// by index; freed slots are reused.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::pairing_heap::PairingHeap;
+///
+/// let mut heap = PairingHeap::new();
+/// heap.insert(5);
+/// let seven = heap.insert(7);
+/// heap.insert(3);
+/// assert_eq!(heap.find_min(), Some(&3));
+///
+/// // Move 7 to the front
+/// heap.decrease_key(seven, 1);
+/// assert_eq!(heap.delete_min(), Some(1));
+/// assert_eq!(heap.delete_min(), Some(3));
+/// assert_eq!(heap.delete_min(), Some(5));
+/// assert_eq!(heap.delete_min(), None);
+/// ```
+///
+/// Decreasing keys below the root after `delete_min` has paired the trees:
+///
+/// ```
+/// use algo_rust::data_structs::pairing_heap::PairingHeap;
+///
+/// let mut heap = PairingHeap::new();
+/// let handles: Vec<_> = [40, 10, 70, 30, 60, 20, 50]
+///     .into_iter()
+///     .map(|value| heap.insert(value))
+///     .collect();
+/// assert_eq!(heap.delete_min(), Some(10));
+/// heap.decrease_key(handles[2], 5);
+/// heap.decrease_key(handles[4], 25);
+/// // An unchanged key is allowed
+/// heap.decrease_key(handles[0], 40);
+///
+/// let mut drained = Vec::new();
+/// while let Some(value) = heap.delete_min() {
+///     drained.push(value);
+/// }
+/// assert_eq!(drained, vec![5, 20, 25, 30, 40, 50]);
+/// ```
+#[derive(Debug)]
+pub struct PairingHeap<T: Ord> {
+    id: u64,
+    nodes: Vec<Slot<T>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    length: usize,
+}
+
+impl<T: Ord> PairingHeap<T> {
+    /// Creates an empty heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let heap: PairingHeap<i32> = PairingHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        PairingHeap {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            length: 0,
+        }
+    }
+
+    /// Adds a value to the heap and returns a handle to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// heap.insert(2);
+    /// heap.insert(1);
+    /// assert_eq!(heap.find_min(), Some(&1));
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn insert(&mut self, value: T) -> Handle {
+        let node = Node {
+            value,
+            child: None,
+            next: None,
+            prev: None,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index].node = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Slot {
+                    generation: 0,
+                    node: Some(node),
+                });
+                self.nodes.len() - 1
+            }
+        };
+        self.root = Some(match self.root {
+            Some(root) => self.link(root, index),
+            None => index,
+        });
+        self.length += 1;
+        Handle {
+            heap: self.id,
+            index,
+            generation: self.nodes[index].generation,
+        }
+    }
+
+    /// Returns the smallest value without removing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// assert_eq!(heap.find_min(), None);
+    /// heap.insert(4);
+    /// assert_eq!(heap.find_min(), Some(&4));
+    /// ```
+    pub fn find_min(&self) -> Option<&T> {
+        self.root.map(|root| &self.node(root).value)
+    }
+
+    /// Removes and returns the smallest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// for value in [3, 1, 2] {
+    ///     heap.insert(value);
+    /// }
+    /// assert_eq!(heap.delete_min(), Some(1));
+    /// assert_eq!(heap.delete_min(), Some(2));
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    pub fn delete_min(&mut self) -> Option<T> {
+        let root = self.root?;
+        let slot = &mut self.nodes[root];
+        let node = slot.node.take().unwrap();
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(root);
+        self.length -= 1;
+
+        let mut children = Vec::new();
+        let mut child = node.child;
+        while let Some(index) = child {
+            let child_node = self.node_mut(index);
+            child = child_node.next.take();
+            child_node.prev = None;
+            children.push(index);
+        }
+        // First pass: link pairs left to right. Second pass: fold the
+        // results into one tree from right to left.
+        let paired: Vec<usize> = children
+            .chunks(2)
+            .map(|pair| match *pair {
+                [a, b] => self.link(a, b),
+                [a] => a,
+                _ => unreachable!(),
+            })
+            .collect();
+        self.root = paired
+            .into_iter()
+            .rev()
+            .reduce(|acc, tree| self.link(tree, acc));
+        Some(node.value)
+    }
+
+    /// Lowers the value behind `handle` to `value`.
+    ///
+    /// The node is cut from its parent and linked with the root, so this
+    /// takes O(1) time, though it adds to the cost of the next
+    /// [`delete_min`](Self::delete_min).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is greater than the current value, if the
+    /// handle's value has already been removed, or if the handle belongs to
+    /// another heap.
+    ///
+    /// ```should_panic
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// let stale = heap.insert(10);
+    /// assert_eq!(heap.delete_min(), Some(10));
+    /// // The new value reuses the freed slot, but the old handle is rejected.
+    /// heap.insert(20);
+    /// heap.decrease_key(stale, 5);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// heap.insert(10);
+    /// let handle = heap.insert(20);
+    /// heap.decrease_key(handle, 5);
+    /// assert_eq!(heap.find_min(), Some(&5));
+    /// ```
+    pub fn decrease_key(&mut self, handle: Handle, value: T) {
+        let index = handle.index;
+        assert!(
+            handle.heap == self.id
+                && self.nodes.get(index).is_some_and(|slot| {
+                    slot.generation == handle.generation && slot.node.is_some()
+                }),
+            "handle does not refer to a value in the heap"
+        );
+        let is_root = self.root == Some(index);
+        let node = self.node_mut(index);
+        assert!(
+            value <= node.value,
+            "new key is greater than the current key"
+        );
+        node.value = value;
+        if is_root {
+            return;
+        }
+
+        let (prev, next) = (node.prev.take().unwrap(), node.next.take());
+        let prev_node = self.node_mut(prev);
+        if prev_node.child == Some(index) {
+            prev_node.child = next;
+        } else {
+            prev_node.next = next;
+        }
+        if let Some(next) = next {
+            self.node_mut(next).prev = Some(prev);
+        }
+        let root = self.root.unwrap();
+        self.root = Some(self.link(root, index));
+    }
+
+    /// Moves every value of `other` into this heap.
+    ///
+    /// Handles returned by `other` are invalidated, and passing one to
+    /// [`decrease_key`](Self::decrease_key) afterwards panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut a = PairingHeap::new();
+    /// let mut b = PairingHeap::new();
+    /// for value in [5, 9, 1] {
+    ///     a.insert(value);
+    /// }
+    /// for value in [4, 0, 8] {
+    ///     b.insert(value);
+    /// }
+    /// a.merge(b);
+    /// assert_eq!(a.len(), 6);
+    /// assert_eq!(a.find_min(), Some(&0));
+    ///
+    /// let mut drained = Vec::new();
+    /// while let Some(value) = a.delete_min() {
+    ///     drained.push(value);
+    /// }
+    /// assert_eq!(drained, vec![0, 1, 4, 5, 8, 9]);
+    /// ```
+    ///
+    /// A handle from the merged heap no longer works:
+    ///
+    /// ```should_panic
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut a = PairingHeap::new();
+    /// a.insert(100);
+    /// a.insert(200);
+    /// let mut b = PairingHeap::new();
+    /// let handle = b.insert(50);
+    /// a.merge(b);
+    /// a.decrease_key(handle, 1);
+    /// ```
+    pub fn merge(&mut self, other: PairingHeap<T>) {
+        let offset = self.nodes.len();
+        let shift = |index: Option<usize>| index.map(|index| index + offset);
+        self.nodes.extend(other.nodes.into_iter().map(|slot| Slot {
+            generation: slot.generation,
+            node: slot.node.map(|node| Node {
+                value: node.value,
+                child: shift(node.child),
+                next: shift(node.next),
+                prev: shift(node.prev),
+            }),
+        }));
+        self.free
+            .extend(other.free.into_iter().map(|index| index + offset));
+        self.length += other.length;
+        self.root = match (self.root, shift(other.root)) {
+            (Some(a), Some(b)) => Some(self.link(a, b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// Returns the number of values in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// heap.insert(1);
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the heap holds no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::pairing_heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// assert!(heap.is_empty());
+    /// heap.insert(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Links two root trees and returns the root of the result.
+    fn link(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = if self.node(b).value < self.node(a).value {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        let first = self.node(parent).child;
+        if let Some(first) = first {
+            self.node_mut(first).prev = Some(child);
+        }
+        let child_node = self.node_mut(child);
+        child_node.next = first;
+        child_node.prev = Some(parent);
+        self.node_mut(parent).child = Some(child);
+        parent
+    }
+
+    fn node(&self, index: usize) -> &Node<T> {
+        self.nodes[index].node.as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        self.nodes[index].node.as_mut().unwrap()
+    }
+}
+
+impl<T: Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,211 @@
+use std::rc::Rc;
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+/// An immutable singly-linked list whose versions share structure.
+///
+/// [`push`](Self::push) and [`tail`](Self::tail) return new lists instead of
+/// modifying `self`. Nodes are reference counted, so a new version reuses
+/// every node of the list it was made from and both stay valid.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::persistent::PersistentList;
+///
+/// let empty = PersistentList::new();
+/// let one = empty.push(1);
+/// let two = one.push(2);
+///
+/// // Older versions are unchanged
+/// assert!(empty.is_empty());
+/// assert_eq!(one.iter().collect::<Vec<_>>(), vec![&1]);
+/// assert_eq!(two.iter().collect::<Vec<_>>(), vec![&2, &1]);
+///
+/// // Two versions can branch off the same tail
+/// let three = one.push(3);
+/// assert_eq!(three.iter().collect::<Vec<_>>(), vec![&3, &1]);
+///
+/// // The shared tail is the same node in memory
+/// assert!(std::ptr::eq(two.tail().head().unwrap(), one.head().unwrap()));
+/// assert!(std::ptr::eq(three.tail().head().unwrap(), one.head().unwrap()));
+/// ```
+#[derive(Debug)]
+pub struct PersistentList<T> {
+    head: Link<T>,
+    length: usize,
+}
+
+impl<T> PersistentList<T> {
+    /// Creates an empty `PersistentList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list: PersistentList<i32> = PersistentList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        PersistentList {
+            head: None,
+            length: 0,
+        }
+    }
+
+    /// Returns a new list with `value` in front of the elements of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list = PersistentList::new().push(1);
+    /// let longer = list.push(2);
+    /// assert_eq!(list.length(), 1);
+    /// assert_eq!(longer.length(), 2);
+    /// assert_eq!(longer.head(), Some(&2));
+    /// ```
+    pub fn push(&self, value: T) -> Self {
+        PersistentList {
+            head: Some(Rc::new(Node {
+                value,
+                next: self.head.clone(),
+            })),
+            length: self.length + 1,
+        }
+    }
+
+    /// Returns a reference to the first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list = PersistentList::new();
+    /// assert_eq!(list.head(), None);
+    /// assert_eq!(list.push(1).head(), Some(&1));
+    /// ```
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns the list without its first element.
+    ///
+    /// The tail of an empty list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list = PersistentList::new().push(1).push(2);
+    /// let tail = list.tail();
+    /// assert_eq!(tail.head(), Some(&1));
+    /// assert_eq!(list.length(), 2);
+    /// assert!(tail.tail().tail().is_empty());
+    /// ```
+    pub fn tail(&self) -> Self {
+        PersistentList {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+            length: self.length.saturating_sub(1),
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list = PersistentList::new().push(1).push(2);
+    /// assert_eq!(list.length(), 2);
+    /// ```
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the list contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list = PersistentList::new();
+    /// assert!(list.is_empty());
+    /// assert!(!list.push(1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Creates an iterator over the list's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::persistent::PersistentList;
+    /// let list = PersistentList::new().push(1).push(2);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        PersistentList {
+            head: self.head.clone(),
+            length: self.length,
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        // Free nodes this list owns alone, stopping at the first one that
+        // another list still shares.
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => current = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+/// An iterator over a persistent list's elements.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
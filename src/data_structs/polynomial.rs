@@ -0,0 +1,330 @@
+use std::ops::{Add, Mul, Sub};
+
+/// A polynomial with coefficients of type `T`.
+///
+/// Coefficients are stored from the constant term upwards, so
+/// `Polynomial::new(vec![1, 2, 3])` represents `1 + 2x + 3x^2`. Trailing zero
+/// coefficients (as given by `T::default()`) are trimmed, which keeps
+/// equality independent of how a polynomial was built.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::polynomial::Polynomial;
+///
+/// let a = Polynomial::new(vec![1, 1]); // x + 1
+/// let b = Polynomial::new(vec![-1, 1]); // x - 1
+///
+/// // Multiply
+/// let product = a.clone() * b.clone();
+/// assert_eq!(product, Polynomial::new(vec![-1, 0, 1])); // x^2 - 1
+///
+/// // Add
+/// assert_eq!(a + b, Polynomial::new(vec![0, 2])); // 2x
+///
+/// // Evaluate
+/// assert_eq!(product.eval(3), 8);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T> {
+    coefficients: Vec<T>,
+}
+
+impl<T> Polynomial<T>
+where
+    T: Copy + Default + PartialEq + Add<Output = T> + Mul<Output = T>,
+{
+    /// Creates a polynomial from its coefficients, constant term first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![5, 0, 2, 0]);
+    /// assert_eq!(p.coefficients(), &[5, 0, 2]);
+    /// ```
+    pub fn new(mut coefficients: Vec<T>) -> Self {
+        while coefficients.last() == Some(&T::default()) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Returns the coefficients, constant term first.
+    ///
+    /// The zero polynomial has no coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1, 2, 3]);
+    /// assert_eq!(p.coefficients(), &[1, 2, 3]);
+    /// assert!(Polynomial::new(vec![0, 0]).coefficients().is_empty());
+    /// ```
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::polynomial::Polynomial;
+    /// // 3x^2 + 2x + 1
+    /// let p = Polynomial::new(vec![1, 2, 3]);
+    /// for x in -3..=3 {
+    ///     assert_eq!(p.eval(x), 3 * x * x + 2 * x + 1);
+    /// }
+    /// ```
+    pub fn eval(&self, x: T) -> T {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(T::default(), |acc, &c| acc * x + c)
+    }
+
+    /// Returns the derivative of the polynomial.
+    ///
+    /// `T` has no notion of converting an exponent into a coefficient, so
+    /// each term is scaled by repeated addition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::polynomial::Polynomial;
+    /// // d/dx (3x^2 + 2x + 1) = 6x + 2
+    /// let p = Polynomial::new(vec![1, 2, 3]);
+    /// assert_eq!(p.derivative(), Polynomial::new(vec![2, 6]));
+    /// assert_eq!(Polynomial::new(vec![7]).derivative(), Polynomial::new(vec![]));
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, &c)| (0..power).fold(T::default(), |acc, _| acc + c))
+            .collect();
+        Self::new(coefficients)
+    }
+}
+
+impl<T> Add for Polynomial<T>
+where
+    T: Copy + Default + PartialEq + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Adds two polynomials term by term.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::polynomial::Polynomial;
+    /// let a = Polynomial::new(vec![1, 2]);
+    /// let b = Polynomial::new(vec![3, -2, 4]);
+    /// assert_eq!(a + b, Polynomial::new(vec![4, 0, 4]));
+    /// ```
+    fn add(self, other: Self) -> Self {
+        let (mut longer, shorter) = if self.coefficients.len() >= other.coefficients.len() {
+            (self.coefficients, other.coefficients)
+        } else {
+            (other.coefficients, self.coefficients)
+        };
+        for (sum, c) in longer.iter_mut().zip(shorter) {
+            *sum = *sum + c;
+        }
+        Self::new(longer)
+    }
+}
+
+impl<T> Mul for Polynomial<T>
+where
+    T: Copy + Default + PartialEq + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Multiplies two polynomials in O(n * m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::polynomial::Polynomial;
+    /// // (x + 1) * (x - 1) = x^2 - 1
+    /// let a = Polynomial::new(vec![1, 1]);
+    /// let b = Polynomial::new(vec![-1, 1]);
+    /// assert_eq!(a * b, Polynomial::new(vec![-1, 0, 1]));
+    /// ```
+    fn mul(self, other: Self) -> Self {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Self::new(Vec::new());
+        }
+        let mut product =
+            vec![T::default(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + a * b;
+            }
+        }
+        Self::new(product)
+    }
+}
+
+/// Multiplies two coefficient vectors directly in O(n * m).
+///
+/// Coefficients are given constant term first. This is mainly a reference for
+/// checking [`multiply_fft`].
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::data_structs::polynomial::multiply_naive;
+/// // (1 + 2x) * (3 + x) = 3 + 7x + 2x^2
+/// assert_eq!(multiply_naive(&[1.0, 2.0], &[3.0, 1.0]), vec![3.0, 7.0, 2.0]);
+/// assert!(multiply_naive(&[], &[1.0]).is_empty());
+/// ```
+pub fn multiply_naive(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0.0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] += x * y;
+        }
+    }
+    product
+}
+
+/// Multiplies two coefficient vectors using the fast Fourier transform.
+///
+/// Coefficients are given constant term first. The convolution runs in
+/// O(n log n), at the cost of floating-point rounding in the result.
+///
+/// # Examples
+///
+/// ```
+/// use algo_rust::data_structs::polynomial::{multiply_fft, multiply_naive};
+///
+/// // (1 + 2x) * (3 + x) = 3 + 7x + 2x^2
+/// let product = multiply_fft(&[1.0, 2.0], &[3.0, 1.0]);
+/// assert_eq!(product.len(), 3);
+/// for (got, want) in product.iter().zip([3.0, 7.0, 2.0]) {
+///     assert!((got - want).abs() < 1e-9);
+/// }
+///
+/// // Cross-check against the naive product, with product lengths that land
+/// // exactly on and just past a power of two.
+/// let a = [2.0, -1.0, 0.0, 3.0, 1.0];
+/// let b = [1.0, 4.0, -2.0, 5.0];
+/// let c = [7.0, 0.0, -3.0, 1.0, 2.0];
+/// for (a, b) in [(&a[..], &b[..]), (&a[..], &c[..]), (&c[..1], &b[..1])] {
+///     let fast = multiply_fft(a, b);
+///     let slow = multiply_naive(a, b);
+///     assert_eq!(fast.len(), slow.len());
+///     for (x, y) in fast.iter().zip(&slow) {
+///         assert!((x - y).abs() < 1e-6);
+///     }
+/// }
+/// ```
+pub fn multiply_fft(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+
+    let mut fa: Vec<Complex> = a.iter().map(|&re| Complex::new(re, 0.0)).collect();
+    let mut fb: Vec<Complex> = b.iter().map(|&re| Complex::new(re, 0.0)).collect();
+    fa.resize(size, Complex::new(0.0, 0.0));
+    fb.resize(size, Complex::new(0.0, 0.0));
+
+    fft(&mut fa, false);
+    fft(&mut fb, false);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x = *x * y;
+    }
+    fft(&mut fa, true);
+
+    fa.iter().take(len).map(|c| c.re / size as f64).collect()
+}
+
+/// A minimal complex number used by the FFT.
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+/// In-place iterative Cooley-Tukey FFT. `values.len()` must be a power of
+/// two. The inverse transform is left unscaled.
+fn fft(values: &mut [Complex], invert: bool) {
+    let n = values.len();
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = 2.0 * std::f64::consts::PI / len as f64 * if invert { -1.0 } else { 1.0 };
+        let step = Complex::new(angle.cos(), angle.sin());
+        for chunk in values.chunks_mut(len) {
+            let mut w = Complex::new(1.0, 0.0);
+            let (lower, upper) = chunk.split_at_mut(len / 2);
+            for (u, v) in lower.iter_mut().zip(upper.iter_mut()) {
+                let t = *v * w;
+                *v = *u - t;
+                *u = *u + t;
+                w = w * step;
+            }
+        }
+        len <<= 1;
+    }
+}
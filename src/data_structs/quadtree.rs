@@ -0,0 +1,279 @@
+/// Nodes stop subdividing at this depth, so many points at the same
+/// position cannot split forever.
+const MAX_DEPTH: usize = 16;
+
+/// An axis-aligned rectangle, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Rect {
+    /// Creates a rectangle from its lower and upper corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::Rect;
+    /// let rect = Rect::new(0.0, 0.0, 2.0, 1.0);
+    /// assert!(rect.contains(2.0, 0.5));
+    /// assert!(!rect.contains(2.5, 0.5));
+    /// ```
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        Rect {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    /// Returns `true` if the point lies inside or on the edge of the
+    /// rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::Rect;
+    /// let rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+    /// assert!(rect.contains(0.0, 1.0));
+    /// assert!(!rect.contains(-0.1, 0.5));
+    /// ```
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        self.min_x <= x && x <= self.max_x && self.min_y <= y && y <= self.max_y
+    }
+
+    /// Returns `true` if the two rectangles share at least one point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::Rect;
+    /// let a = Rect::new(0.0, 0.0, 1.0, 1.0);
+    /// assert!(a.intersects(&Rect::new(1.0, 1.0, 2.0, 2.0)));
+    /// assert!(!a.intersects(&Rect::new(1.5, 0.0, 2.0, 1.0)));
+    /// ```
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    fn quadrants(&self) -> [Rect; 4] {
+        let mid_x = (self.min_x + self.max_x) / 2.0;
+        let mid_y = (self.min_y + self.max_y) / 2.0;
+        [
+            Rect::new(self.min_x, self.min_y, mid_x, mid_y),
+            Rect::new(mid_x, self.min_y, self.max_x, mid_y),
+            Rect::new(self.min_x, mid_y, mid_x, self.max_y),
+            Rect::new(mid_x, mid_y, self.max_x, self.max_y),
+        ]
+    }
+}
+
+#[derive(Debug)]
+struct QuadNode<T> {
+    bounds: Rect,
+    depth: usize,
+    points: Vec<(f64, f64, T)>,
+    children: Option<Box<[QuadNode<T>; 4]>>,
+}
+
+impl<T> QuadNode<T> {
+    fn new(bounds: Rect, depth: usize) -> Self {
+        QuadNode {
+            bounds,
+            depth,
+            points: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn insert(&mut self, x: f64, y: f64, value: T, capacity: usize) {
+        if let Some(children) = &mut self.children {
+            let child = children
+                .iter_mut()
+                .find(|child| child.bounds.contains(x, y))
+                .unwrap();
+            child.insert(x, y, value, capacity);
+            return;
+        }
+        self.points.push((x, y, value));
+        if self.points.len() > capacity && self.depth < MAX_DEPTH {
+            self.subdivide(capacity);
+        }
+    }
+
+    fn subdivide(&mut self, capacity: usize) {
+        let children = self
+            .bounds
+            .quadrants()
+            .map(|bounds| QuadNode::new(bounds, self.depth + 1));
+        self.children = Some(Box::new(children));
+        for (x, y, value) in std::mem::take(&mut self.points) {
+            self.insert(x, y, value, capacity);
+        }
+    }
+
+    fn query<'a>(&'a self, range: &Rect, found: &mut Vec<&'a T>) {
+        if !self.bounds.intersects(range) {
+            return;
+        }
+        found.extend(
+            self.points
+                .iter()
+                .filter(|(x, y, _)| range.contains(*x, *y))
+                .map(|(_, _, value)| value),
+        );
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.query(range, found);
+            }
+        }
+    }
+}
+
+/// A point quadtree storing values at 2D positions.
+///
+/// Each node covers a rectangle and holds up to `capacity` points. When a
+/// node overflows it splits into four equal quadrants and hands its points
+/// down, so dense areas are subdivided more finely. Range queries skip
+/// every node whose rectangle misses the query.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::quadtree::{QuadTree, Rect};
+///
+/// let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 100.0, 100.0), 4);
+///
+/// // A cluster near the origin and a few points elsewhere
+/// for i in 0..20 {
+///     let offset = i as f64 * 0.5;
+///     tree.insert(1.0 + offset, 1.0 + offset, i);
+/// }
+/// tree.insert(50.0, 50.0, 100);
+/// tree.insert(90.0, 10.0, 101);
+/// assert!(!tree.insert(150.0, 10.0, 102));
+/// assert_eq!(tree.len(), 22);
+///
+/// // Exactly the points inside the rectangle
+/// let mut found = tree.query_range(&Rect::new(0.0, 0.0, 3.0, 3.0));
+/// found.sort();
+/// assert_eq!(found, vec![&0, &1, &2, &3, &4]);
+///
+/// let mut found = tree.query_range(&Rect::new(40.0, 0.0, 100.0, 60.0));
+/// found.sort();
+/// assert_eq!(found, vec![&100, &101]);
+///
+/// assert!(tree.query_range(&Rect::new(60.0, 60.0, 70.0, 70.0)).is_empty());
+/// ```
+#[derive(Debug)]
+pub struct QuadTree<T> {
+    root: QuadNode<T>,
+    capacity: usize,
+    length: usize,
+}
+
+impl<T> QuadTree<T> {
+    /// Creates an empty quadtree covering `bounds`, where each node holds
+    /// up to `capacity` points before subdividing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::{QuadTree, Rect};
+    /// let tree: QuadTree<i32> = QuadTree::new(Rect::new(0.0, 0.0, 1.0, 1.0), 4);
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new(bounds: Rect, capacity: usize) -> Self {
+        assert!(capacity > 0, "node capacity must be positive");
+        QuadTree {
+            root: QuadNode::new(bounds, 0),
+            capacity,
+            length: 0,
+        }
+    }
+
+    /// Inserts `value` at `(x, y)`.
+    ///
+    /// Returns `false`, without inserting, if the point lies outside the
+    /// tree's bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::{QuadTree, Rect};
+    /// let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 1.0, 1.0), 4);
+    /// assert!(tree.insert(0.5, 0.5, "inside"));
+    /// assert!(!tree.insert(2.0, 0.5, "outside"));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn insert(&mut self, x: f64, y: f64, value: T) -> bool {
+        if !self.root.bounds.contains(x, y) {
+            return false;
+        }
+        self.root.insert(x, y, value, self.capacity);
+        self.length += 1;
+        true
+    }
+
+    /// Returns the values of every point inside `range`, edges included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::{QuadTree, Rect};
+    /// let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 10.0, 10.0), 1);
+    /// tree.insert(1.0, 1.0, 'a');
+    /// tree.insert(5.0, 5.0, 'b');
+    /// tree.insert(9.0, 9.0, 'c');
+    /// let mut found = tree.query_range(&Rect::new(1.0, 1.0, 5.0, 5.0));
+    /// found.sort();
+    /// assert_eq!(found, vec![&'a', &'b']);
+    /// ```
+    pub fn query_range(&self, range: &Rect) -> Vec<&T> {
+        let mut found = Vec::new();
+        self.root.query(range, &mut found);
+        found
+    }
+
+    /// Returns the number of points in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::{QuadTree, Rect};
+    /// let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 1.0, 1.0), 4);
+    /// tree.insert(0.5, 0.5, ());
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the tree holds no points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::quadtree::{QuadTree, Rect};
+    /// let mut tree = QuadTree::new(Rect::new(0.0, 0.0, 1.0, 1.0), 4);
+    /// assert!(tree.is_empty());
+    /// tree.insert(0.5, 0.5, ());
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::rng;
+
+/// The largest number of chars stored in one node when text is inserted.
+const CHUNK_CHARS: usize = 64;
+
+type Link = Option<Box<Node>>;
+
+#[derive(Debug)]
+struct Node {
+    text: String,
+    chars: usize,
+    total: usize,
+    priority: u64,
+    left: Link,
+    right: Link,
+}
+
+impl Node {
+    fn new(text: String, chars: usize, priority: u64) -> Node {
+        Node {
+            text,
+            chars,
+            total: chars,
+            priority,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update_total(&mut self) {
+        self.total = total(&self.left) + self.chars + total(&self.right);
+    }
+}
+
+/// A string stored as a balanced tree of text chunks.
+///
+/// Positions are counted in chars. Inserting or deleting splits and rejoins
+/// the tree instead of shifting the whole string, so edits take expected
+/// O(log n) time plus the length of the inserted text. The tree is kept
+/// balanced with random priorities, as in a treap.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::rope::Rope;
+///
+/// let mut rope = Rope::from("Hello world");
+///
+/// // Insert text
+/// rope.insert(5, ",");
+/// rope.insert(12, "!");
+/// assert_eq!(rope.to_string(), "Hello, world!");
+///
+/// // Delete a range
+/// rope.delete(5..12);
+/// assert_eq!(rope.to_string(), "Hello!");
+///
+/// // Read a char
+/// assert_eq!(rope.char_at(1), Some('e'));
+/// assert_eq!(rope.len(), 6);
+/// ```
+///
+/// Edits to text spanning several nodes match the same edits on a
+/// `String`:
+///
+/// ```
+/// use algo_rust::data_structs::rope::Rope;
+///
+/// let base = "0123456789".repeat(20);
+/// let mut rope = Rope::from(base.as_str());
+/// let mut reference = base.clone();
+///
+/// rope.insert(64, "<here>");
+/// reference.insert_str(64, "<here>");
+/// rope.delete(50..150);
+/// reference.replace_range(50..150, "");
+/// rope.insert(0, "start:");
+/// reference.insert_str(0, "start:");
+/// rope.insert(rope.len(), ":end");
+/// reference.push_str(":end");
+///
+/// assert_eq!(rope.len(), reference.len());
+/// assert_eq!(rope.char_at(56), reference.chars().nth(56));
+/// assert_eq!(rope.to_string(), reference);
+/// ```
+#[derive(Debug)]
+pub struct Rope {
+    root: Link,
+    rng: u64,
+}
+
+impl Rope {
+    /// Creates an empty `Rope`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::rope::Rope;
+    /// let rope = Rope::new();
+    /// assert!(rope.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Rope { root: None, rng: 0 }
+    }
+
+    /// Returns the number of chars in the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::rope::Rope;
+    /// assert_eq!(Rope::from("héllo").len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        total(&self.root)
+    }
+
+    /// Returns `true` if the rope holds no text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::rope::Rope;
+    /// assert!(Rope::new().is_empty());
+    /// assert!(!Rope::from("a").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Inserts `text` so that it starts at char position `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::rope::Rope;
+    /// let mut rope = Rope::from("ac");
+    /// rope.insert(1, "b");
+    /// rope.insert(0, ">");
+    /// rope.insert(4, "<");
+    /// assert_eq!(rope.to_string(), ">abc<");
+    /// ```
+    pub fn insert(&mut self, index: usize, text: &str) {
+        assert!(
+            index <= self.len(),
+            "insert index {index} out of bounds for length {}",
+            self.len()
+        );
+        let middle = self.build(text);
+        let (left, right) = split(self.root.take(), index, &mut self.rng);
+        self.root = merge(merge(left, middle), right);
+    }
+
+    /// Removes the chars in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or ends past the length of the
+    /// rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::rope::Rope;
+    /// let mut rope = Rope::from("abcdef");
+    /// rope.delete(1..3);
+    /// assert_eq!(rope.to_string(), "adef");
+    /// rope.delete(2..2);
+    /// assert_eq!(rope.to_string(), "adef");
+    /// ```
+    pub fn delete(&mut self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "delete range {range:?} out of bounds for length {}",
+            self.len()
+        );
+        let (left, rest) = split(self.root.take(), range.start, &mut self.rng);
+        let (_, right) = split(rest, range.end - range.start, &mut self.rng);
+        self.root = merge(left, right);
+    }
+
+    /// Returns the char at position `index`, or `None` if it is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::rope::Rope;
+    /// let rope = Rope::from("héllo");
+    /// assert_eq!(rope.char_at(1), Some('é'));
+    /// assert_eq!(rope.char_at(4), Some('o'));
+    /// assert_eq!(rope.char_at(5), None);
+    /// ```
+    pub fn char_at(&self, mut index: usize) -> Option<char> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            let left = total(&node.left);
+            if index < left {
+                current = node.left.as_deref();
+            } else if index < left + node.chars {
+                return node.text.chars().nth(index - left);
+            } else {
+                index -= left + node.chars;
+                current = node.right.as_deref();
+            }
+        }
+        None
+    }
+
+    /// Builds a balanced subtree holding `text` in chunks.
+    fn build(&mut self, text: &str) -> Link {
+        let mut tree = None;
+        let mut chunk = String::new();
+        let mut chars = 0;
+        for c in text.chars() {
+            chunk.push(c);
+            chars += 1;
+            if chars == CHUNK_CHARS {
+                let node = Node::new(std::mem::take(&mut chunk), chars, rng::next(&mut self.rng));
+                tree = merge(tree, Some(Box::new(node)));
+                chars = 0;
+            }
+        }
+        if chars > 0 {
+            let node = Node::new(chunk, chars, rng::next(&mut self.rng));
+            tree = merge(tree, Some(Box::new(node)));
+        }
+        tree
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        let mut rope = Rope::new();
+        rope.root = rope.build(text);
+        rope
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stack = Vec::new();
+        let mut current = self.root.as_deref();
+        loop {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+            let Some(node) = stack.pop() else {
+                return Ok(());
+            };
+            f.write_str(&node.text)?;
+            current = node.right.as_deref();
+        }
+    }
+}
+
+fn total(link: &Link) -> usize {
+    link.as_ref().map_or(0, |node| node.total)
+}
+
+/// Joins two subtrees, with all of `left`'s text before `right`'s.
+fn merge(left: Link, right: Link) -> Link {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update_total();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update_total();
+                Some(right)
+            }
+        }
+    }
+}
+
+/// Splits a subtree into its first `index` chars and the rest, cutting a
+/// chunk in two if the split point falls inside it.
+fn split(link: Link, index: usize, rng: &mut u64) -> (Link, Link) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    let left_total = total(&node.left);
+    if index <= left_total {
+        let (less, rest) = split(node.left.take(), index, rng);
+        node.left = rest;
+        node.update_total();
+        (less, Some(node))
+    } else if index >= left_total + node.chars {
+        let (less, rest) = split(node.right.take(), index - left_total - node.chars, rng);
+        node.right = less;
+        node.update_total();
+        (Some(node), rest)
+    } else {
+        let keep = index - left_total;
+        let byte = node.text.char_indices().nth(keep).unwrap().0;
+        let tail_text = node.text.split_off(byte);
+        let tail = Node::new(tail_text, node.chars - keep, rng::next(rng));
+        node.chars = keep;
+        let rest = merge(Some(Box::new(tail)), node.right.take());
+        node.update_total();
+        (Some(node), rest)
+    }
+}
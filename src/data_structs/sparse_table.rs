@@ -0,0 +1,126 @@
+/// A static range-query table for idempotent operations.
+///
+/// Level `k` of the table stores `op` folded over every window of `2^k`
+/// values. Any inclusive range `l..=r` is covered by two overlapping
+/// windows of the largest power of two that fits, so a query is a single
+/// `op` call. This is only correct when `op` is associative and
+/// idempotent (`op(a, a) == a`), as with `min`, `max` and `gcd`.
+///
+/// Building takes O(n log n) time and space; every query takes O(1).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::sparse_table::SparseTable;
+///
+/// let values = vec![5, 2, 8, 6, 3, 7, 1, 4];
+/// let min = SparseTable::new(values.clone(), |a: &i32, b: &i32| *a.min(b));
+/// let max = SparseTable::new(values.clone(), |a: &i32, b: &i32| *a.max(b));
+/// assert_eq!(min.query(0, 3), 2);
+/// assert_eq!(max.query(3, 5), 7);
+///
+/// // Every range agrees with a naive scan
+/// for l in 0..values.len() {
+///     for r in l..values.len() {
+///         assert_eq!(min.query(l, r), *values[l..=r].iter().min().unwrap());
+///         assert_eq!(max.query(l, r), *values[l..=r].iter().max().unwrap());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseTable<T, F> {
+    table: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> SparseTable<T, F> {
+    /// Builds a table over `values` for the idempotent operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::sparse_table::SparseTable;
+    ///
+    /// fn gcd(a: &u32, b: &u32) -> u32 {
+    ///     let (mut a, mut b) = (*a, *b);
+    ///     while b != 0 {
+    ///         (a, b) = (b, a % b);
+    ///     }
+    ///     a
+    /// }
+    ///
+    /// let table = SparseTable::new(vec![12, 18, 24, 9, 27], gcd);
+    /// assert_eq!(table.query(0, 2), 6);
+    /// assert_eq!(table.query(2, 4), 3);
+    /// assert_eq!(table.query(3, 4), 9);
+    /// ```
+    pub fn new(values: Vec<T>, op: F) -> Self {
+        let n = values.len();
+        let mut table = vec![values];
+        let mut width = 1;
+        while 2 * width <= n {
+            let prev = table.last().unwrap();
+            let level = (0..=n - 2 * width)
+                .map(|i| op(&prev[i], &prev[i + width]))
+                .collect();
+            table.push(level);
+            width *= 2;
+        }
+        SparseTable { table, op }
+    }
+
+    /// Returns `op` folded over the values at indices `l..=r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `l > r` or `r` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::sparse_table::SparseTable;
+    /// let table = SparseTable::new(vec![3, 1, 4, 1, 5], |a: &i32, b: &i32| *a.max(b));
+    /// assert_eq!(table.query(0, 0), 3);
+    /// assert_eq!(table.query(1, 3), 4);
+    /// assert_eq!(table.query(0, 4), 5);
+    /// ```
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(l <= r, "range start {l} is after its end {r}");
+        assert!(
+            r < self.len(),
+            "index {r} out of bounds for length {}",
+            self.len()
+        );
+        let level = (r - l + 1).ilog2() as usize;
+        let width = 1 << level;
+        (self.op)(&self.table[level][l], &self.table[level][r + 1 - width])
+    }
+
+    /// Returns the number of values the table was built over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::sparse_table::SparseTable;
+    /// let table = SparseTable::new(vec![1, 2, 3], |a: &i32, b: &i32| *a.min(b));
+    /// assert_eq!(table.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    /// Returns `true` if the table was built over no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::sparse_table::SparseTable;
+    /// let table = SparseTable::new(Vec::new(), |a: &i32, b: &i32| *a.min(b));
+    /// assert!(table.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.table[0].is_empty()
+    }
+}
@@ -0,0 +1,124 @@
+/// Accumulates string fragments and joins them in a single allocation.
+///
+/// Each `append` copies the fragment into its own `String` and records it,
+/// so the builder never reallocates one growing buffer. `build` sizes the
+/// output from the tracked byte count and copies every fragment once.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::string_builder::StringBuilder;
+///
+/// let mut builder = StringBuilder::new();
+/// builder.append("Hello").append(", ").append("wörld");
+/// assert_eq!(builder.len(), 12);
+/// assert_eq!(builder.build(), "Hello, wörld");
+///
+/// // Many small fragments
+/// let mut builder = StringBuilder::new();
+/// let mut expected = String::new();
+/// for i in 0..1000 {
+///     let fragment = i.to_string();
+///     builder.append(&fragment);
+///     expected.push_str(&fragment);
+/// }
+/// assert_eq!(builder.len(), expected.chars().count());
+/// assert_eq!(builder.build(), expected);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StringBuilder {
+    fragments: Vec<String>,
+    bytes: usize,
+    chars: usize,
+}
+
+impl StringBuilder {
+    /// Creates an empty builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::string_builder::StringBuilder;
+    /// let builder = StringBuilder::new();
+    /// assert!(builder.is_empty());
+    /// assert_eq!(builder.build(), "");
+    /// ```
+    pub fn new() -> Self {
+        StringBuilder {
+            fragments: Vec::new(),
+            bytes: 0,
+            chars: 0,
+        }
+    }
+
+    /// Appends a fragment and returns the builder for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::string_builder::StringBuilder;
+    /// let mut builder = StringBuilder::new();
+    /// builder.append("ab").append("cd");
+    /// assert_eq!(builder.build(), "abcd");
+    /// ```
+    pub fn append(&mut self, fragment: &str) -> &mut Self {
+        if !fragment.is_empty() {
+            self.bytes += fragment.len();
+            self.chars += fragment.chars().count();
+            self.fragments.push(fragment.to_string());
+        }
+        self
+    }
+
+    /// Returns the concatenation of every fragment appended so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::string_builder::StringBuilder;
+    /// let mut builder = StringBuilder::new();
+    /// builder.append("x").append("y");
+    /// assert_eq!(builder.build(), "xy");
+    /// builder.append("z");
+    /// assert_eq!(builder.build(), "xyz");
+    /// ```
+    pub fn build(&self) -> String {
+        let mut output = String::with_capacity(self.bytes);
+        for fragment in &self.fragments {
+            output.push_str(fragment);
+        }
+        output
+    }
+
+    /// Returns the total number of characters appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::string_builder::StringBuilder;
+    /// let mut builder = StringBuilder::new();
+    /// builder.append("héllo");
+    /// assert_eq!(builder.len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chars
+    }
+
+    /// Returns `true` if nothing but empty fragments has been appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::string_builder::StringBuilder;
+    /// let mut builder = StringBuilder::new();
+    /// builder.append("");
+    /// assert!(builder.is_empty());
+    /// builder.append("a");
+    /// assert!(!builder.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars == 0
+    }
+}
@@ -0,0 +1,214 @@
+use crate::data_structs::linked_list::LinkedList;
+
+/// A hashed timing wheel that releases payloads after a delay in ticks.
+///
+/// The wheel is a ring of slots, each a [`LinkedList`] of pending entries.
+/// An entry due at tick `t` lives in slot `t % slots`, so scheduling is
+/// O(1) and each tick only inspects one slot. Delays longer than the ring
+/// wrap around; their entries stay in the slot until the wheel reaches
+/// their actual deadline.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::timing_wheel::TimingWheel;
+///
+/// let mut wheel = TimingWheel::new(8);
+/// wheel.schedule(3, "a");
+/// wheel.schedule(3, "b");
+/// wheel.schedule(10, "c"); // wraps around the 8-slot ring
+/// wheel.schedule(1, "d");
+/// assert_eq!(wheel.len(), 4);
+///
+/// assert_eq!(wheel.advance(1), vec!["d"]);
+/// assert_eq!(wheel.advance(2), vec!["a", "b"]);
+/// // Tick 8 lands on the slot holding "c", but it is not due yet
+/// assert!(wheel.advance(5).is_empty());
+/// assert_eq!(wheel.now(), 8);
+/// assert_eq!(wheel.advance(2), vec!["c"]);
+/// assert!(wheel.is_empty());
+///
+/// // Delays are relative to the current tick
+/// wheel.schedule(4, "e");
+/// wheel.schedule(2, "f");
+/// assert_eq!(wheel.advance(100), vec!["f", "e"]);
+/// assert_eq!(wheel.now(), 110);
+/// ```
+#[derive(Debug)]
+pub struct TimingWheel<T> {
+    slots: Vec<LinkedList<(u64, T)>>,
+    now: u64,
+    length: usize,
+}
+
+impl<T> TimingWheel<T> {
+    /// Creates an empty wheel with `slots` slots, starting at tick 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slots` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::timing_wheel::TimingWheel;
+    /// let wheel: TimingWheel<u32> = TimingWheel::new(16);
+    /// assert_eq!(wheel.now(), 0);
+    /// assert!(wheel.is_empty());
+    /// ```
+    pub fn new(slots: usize) -> Self {
+        assert!(slots > 0, "a timing wheel needs at least one slot");
+        TimingWheel {
+            slots: (0..slots).map(|_| LinkedList::new()).collect(),
+            now: 0,
+            length: 0,
+        }
+    }
+
+    /// Schedules `payload` to be released `delay_ticks` ticks from now.
+    ///
+    /// A delay of zero is treated as one, releasing the payload on the
+    /// next tick. Deadlines past [`u64::MAX`] are clamped to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::timing_wheel::TimingWheel;
+    /// let mut wheel = TimingWheel::new(4);
+    /// wheel.schedule(0, "soon");
+    /// assert_eq!(wheel.advance(1), vec!["soon"]);
+    ///
+    /// wheel.schedule(u64::MAX, "last");
+    /// assert_eq!(wheel.advance(u64::MAX), vec!["last"]);
+    /// assert_eq!(wheel.now(), u64::MAX);
+    /// ```
+    pub fn schedule(&mut self, delay_ticks: u64, payload: T) {
+        let deadline = self.now.saturating_add(delay_ticks.max(1));
+        let slot = self.slot_of(deadline);
+        self.slots[slot].push((deadline, payload));
+        self.length += 1;
+    }
+
+    /// Moves the wheel forward by `ticks` and returns every payload whose
+    /// deadline was reached.
+    ///
+    /// Payloads come out ordered by deadline, and in scheduling order for
+    /// equal deadlines. The wheel stops at [`u64::MAX`].
+    ///
+    /// Ticks are walked one slot at a time, but once a full turn of the
+    /// ring releases nothing the wheel jumps straight to the earliest
+    /// pending deadline, so long idle stretches stay cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::timing_wheel::TimingWheel;
+    /// let mut wheel = TimingWheel::new(4);
+    /// wheel.schedule(2, 'x');
+    /// wheel.schedule(1, 'y');
+    /// assert_eq!(wheel.advance(0), Vec::<char>::new());
+    /// assert_eq!(wheel.advance(3), vec!['y', 'x']);
+    ///
+    /// wheel.schedule(1 << 50, 'z');
+    /// assert!(wheel.advance(1 << 40).is_empty());
+    /// assert_eq!(wheel.advance(1 << 50), vec!['z']);
+    /// ```
+    pub fn advance(&mut self, ticks: u64) -> Vec<T> {
+        let target = self.now.saturating_add(ticks);
+        let mut due = Vec::new();
+        let mut idle = 0;
+        while self.now < target {
+            if idle >= self.slots.len() || self.length == 0 {
+                // Nothing is due within a turn, so skip to the tick before
+                // the earliest deadline
+                match self.earliest_deadline() {
+                    Some(deadline) if deadline <= target => {
+                        self.now = deadline - 1;
+                        idle = 0;
+                    }
+                    _ => {
+                        self.now = target;
+                        break;
+                    }
+                }
+            }
+            self.now += 1;
+            let slot = self.slot_of(self.now);
+            // Slots hold entries newest first
+            let mut pending = Vec::new();
+            let mut fired = Vec::new();
+            for (deadline, payload) in std::mem::take(&mut self.slots[slot]) {
+                if deadline == self.now {
+                    fired.push(payload);
+                } else {
+                    pending.push((deadline, payload));
+                }
+            }
+            idle = if fired.is_empty() { idle + 1 } else { 0 };
+            self.length -= fired.len();
+            self.slots[slot] = LinkedList::from(pending);
+            due.extend(fired.into_iter().rev());
+        }
+        due
+    }
+
+    /// Returns the current tick.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::timing_wheel::TimingWheel;
+    /// let mut wheel: TimingWheel<()> = TimingWheel::new(4);
+    /// wheel.advance(7);
+    /// assert_eq!(wheel.now(), 7);
+    /// ```
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Returns the number of payloads not yet released.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::timing_wheel::TimingWheel;
+    /// let mut wheel = TimingWheel::new(4);
+    /// wheel.schedule(1, ());
+    /// wheel.schedule(9, ());
+    /// assert_eq!(wheel.len(), 2);
+    /// wheel.advance(1);
+    /// assert_eq!(wheel.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if no payloads are pending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::timing_wheel::TimingWheel;
+    /// let mut wheel = TimingWheel::new(4);
+    /// assert!(wheel.is_empty());
+    /// wheel.schedule(1, ());
+    /// assert!(!wheel.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    fn earliest_deadline(&self) -> Option<u64> {
+        self.slots
+            .iter()
+            .flat_map(|slot| slot.iter())
+            .map(|&(deadline, _)| deadline)
+            .min()
+    }
+
+    fn slot_of(&self, tick: u64) -> usize {
+        (tick % self.slots.len() as u64) as usize
+    }
+}
@@ -0,0 +1,484 @@
+use std::cmp::Ordering;
+
+use crate::rng;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    priority: u64,
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(value: T, priority: u64) -> Node<T> {
+        Node {
+            value,
+            priority,
+            size: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update_size(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+/// A randomized balanced binary search tree.
+///
+/// Values are ordered as in a binary search tree, while randomly drawn
+/// priorities are ordered as in a max-heap. The random priorities keep the
+/// expected height at O(log n). Priorities come from a seeded generator, so
+/// the same seed and the same operations always produce the same tree.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::treap::Treap;
+///
+/// let mut treap = Treap::with_seed(7);
+///
+/// // Insert values
+/// for value in [5, 2, 8, 1, 9, 3] {
+///     treap.insert(value);
+/// }
+/// assert_eq!(treap.len(), 6);
+///
+/// // Remove a value
+/// assert!(treap.remove(&8));
+/// assert!(!treap.contains(&8));
+///
+/// // In-order traversal is sorted
+/// assert_eq!(treap.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &5, &9]);
+/// ```
+///
+/// Sorted insertions stay shallow, and a fixed seed gives a reproducible
+/// shape:
+///
+/// ```
+/// use algo_rust::data_structs::treap::Treap;
+///
+/// let build = || {
+///     let mut treap = Treap::with_seed(42);
+///     for value in 0..1000 {
+///         assert!(treap.insert(value));
+///     }
+///     for value in (0..1000).step_by(3) {
+///         assert!(treap.remove(&value));
+///     }
+///     treap
+/// };
+///
+/// let a = build();
+/// let b = build();
+/// assert_eq!(a.len(), 666);
+/// assert!(a.iter().copied().eq((0..1000).filter(|value| value % 3 != 0)));
+/// assert_eq!(a.height(), b.height());
+/// assert!(a.height() < 4 * (usize::BITS - a.len().leading_zeros()) as usize);
+/// ```
+#[derive(Debug)]
+pub struct Treap<T> {
+    root: Link<T>,
+    rng: u64,
+}
+
+impl<T: Ord> Treap<T> {
+    /// Creates an empty `Treap` with a fixed default seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let treap: Treap<i32> = Treap::new();
+    /// assert!(treap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Creates an empty `Treap` whose priorities are drawn from a generator
+    /// seeded with `seed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::with_seed(123);
+    /// treap.insert(1);
+    /// assert_eq!(treap.len(), 1);
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Treap {
+            root: None,
+            rng: seed,
+        }
+    }
+
+    /// Inserts a value, returning `false` if it was already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// assert!(treap.insert(1));
+    /// assert!(!treap.insert(1));
+    /// assert_eq!(treap.len(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        let priority = rng::next(&mut self.rng);
+        insert(&mut self.root, value, priority)
+    }
+
+    /// Removes a value, returning `true` if it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// assert!(treap.remove(&1));
+    /// assert!(!treap.remove(&1));
+    /// assert!(treap.is_empty());
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        remove(&mut self.root, value)
+    }
+
+    /// Returns `true` if the treap contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// assert!(treap.contains(&1));
+    /// assert!(!treap.contains(&2));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Returns the number of values in the treap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// treap.insert(2);
+    /// assert_eq!(treap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns `true` if the treap contains no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// assert!(treap.is_empty());
+    /// treap.insert(1);
+    /// assert!(!treap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the number of nodes on the longest root-to-leaf path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// assert_eq!(treap.height(), 0);
+    /// treap.insert(1);
+    /// assert_eq!(treap.height(), 1);
+    /// ```
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Creates an iterator over the values in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::new();
+    /// for value in [3, 1, 2] {
+    ///     treap.insert(value);
+    /// }
+    /// assert_eq!(treap.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+
+    /// Splits the treap into the values less than `key` and the values
+    /// greater than or equal to `key`, in O(log n) expected time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let mut treap = Treap::with_seed(1);
+    /// for value in 0..10 {
+    ///     treap.insert(value);
+    /// }
+    ///
+    /// let (less, rest) = treap.split(&4);
+    /// assert_eq!(less.iter().collect::<Vec<_>>(), vec![&0, &1, &2, &3]);
+    /// assert_eq!(rest.len(), 6);
+    /// assert_eq!(rest.iter().next(), Some(&4));
+    /// ```
+    pub fn split(mut self, key: &T) -> (Treap<T>, Treap<T>) {
+        let seed = rng::next(&mut self.rng);
+        let (less, rest) = split(self.root.take(), key);
+        (
+            Treap {
+                root: less,
+                rng: self.rng,
+            },
+            Treap {
+                root: rest,
+                rng: seed,
+            },
+        )
+    }
+
+    /// Joins two treaps where every value in `left` is less than every value
+    /// in `right`, in O(log n) expected time.
+    ///
+    /// The result continues drawing priorities from `left`'s generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the largest value in `left` is not less than the smallest
+    /// value in `right`.
+    ///
+    /// # Examples
+    ///
+    /// Splitting and merging back reproduces the original values:
+    ///
+    /// ```
+    /// use algo_rust::data_structs::treap::Treap;
+    /// let values = [15, 3, 9, 27, 1, 12, 20, 6];
+    /// let build = || {
+    ///     let mut treap = Treap::with_seed(9);
+    ///     for value in values {
+    ///         treap.insert(value);
+    ///     }
+    ///     treap
+    /// };
+    /// let original: Vec<i32> = build().iter().copied().collect();
+    ///
+    /// for key in [0, 6, 13, 100] {
+    ///     let (left, right) = build().split(&key);
+    ///     let merged = Treap::merge(left, right);
+    ///     assert_eq!(merged.len(), original.len());
+    ///     assert!(merged.iter().eq(original.iter()));
+    /// }
+    /// ```
+    pub fn merge(mut left: Treap<T>, mut right: Treap<T>) -> Treap<T> {
+        if let (Some(max), Some(min)) = (left.last(), right.first()) {
+            assert!(
+                max < min,
+                "every value in `left` must be less than every value in `right`"
+            );
+        }
+        Treap {
+            root: merge(left.root.take(), right.root.take()),
+            rng: left.rng,
+        }
+    }
+
+    fn first(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    fn last(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some(&node.value)
+    }
+}
+
+impl<T: Ord> Default for Treap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn insert<T: Ord>(link: &mut Link<T>, value: T, priority: u64) -> bool {
+    let Some(node) = link else {
+        *link = Some(Box::new(Node::new(value, priority)));
+        return true;
+    };
+    match value.cmp(&node.value) {
+        Ordering::Equal => false,
+        Ordering::Less => {
+            let inserted = insert(&mut node.left, value, priority);
+            node.update_size();
+            if node
+                .left
+                .as_ref()
+                .is_some_and(|left| left.priority > node.priority)
+            {
+                rotate_right(link);
+            }
+            inserted
+        }
+        Ordering::Greater => {
+            let inserted = insert(&mut node.right, value, priority);
+            node.update_size();
+            if node
+                .right
+                .as_ref()
+                .is_some_and(|right| right.priority > node.priority)
+            {
+                rotate_left(link);
+            }
+            inserted
+        }
+    }
+}
+
+fn remove<T: Ord>(link: &mut Link<T>, value: &T) -> bool {
+    let Some(node) = link else {
+        return false;
+    };
+    let removed = match value.cmp(&node.value) {
+        Ordering::Less => remove(&mut node.left, value),
+        Ordering::Greater => remove(&mut node.right, value),
+        Ordering::Equal => {
+            let mut node = link.take().unwrap();
+            *link = merge(node.left.take(), node.right.take());
+            return true;
+        }
+    };
+    node.update_size();
+    removed
+}
+
+/// Joins two subtrees where every value in `left` is less than every value
+/// in `right`.
+fn merge<T>(left: Link<T>, right: Link<T>) -> Link<T> {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update_size();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update_size();
+                Some(right)
+            }
+        }
+    }
+}
+
+/// Splits a subtree into the values less than `key` and the rest.
+fn split<T: Ord>(link: Link<T>, key: &T) -> (Link<T>, Link<T>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    if node.value < *key {
+        let (less, rest) = split(node.right.take(), key);
+        node.right = less;
+        node.update_size();
+        (Some(node), rest)
+    } else {
+        let (less, rest) = split(node.left.take(), key);
+        node.left = rest;
+        node.update_size();
+        (less, Some(node))
+    }
+}
+
+fn rotate_right<T>(link: &mut Link<T>) {
+    let mut root = link.take().unwrap();
+    let mut left = root.left.take().unwrap();
+    root.left = left.right.take();
+    root.update_size();
+    left.right = Some(root);
+    left.update_size();
+    *link = Some(left);
+}
+
+fn rotate_left<T>(link: &mut Link<T>) {
+    let mut root = link.take().unwrap();
+    let mut right = root.right.take().unwrap();
+    root.right = right.left.take();
+    root.update_size();
+    right.left = Some(root);
+    right.update_size();
+    *link = Some(right);
+}
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref()
+        .map_or(0, |node| 1 + height(&node.left).max(height(&node.right)))
+}
+
+/// An in-order iterator over a treap's values.
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stack.pop().map(|node| {
+            self.push_left(node.right.as_deref());
+            &node.value
+        })
+    }
+}
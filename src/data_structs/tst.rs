@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+
+type Link = Option<Box<Node>>;
+
+#[derive(Debug)]
+struct Node {
+    ch: char,
+    is_end: bool,
+    lo: Link,
+    eq: Link,
+    hi: Link,
+}
+
+impl Node {
+    fn new(ch: char) -> Node {
+        Node {
+            ch,
+            is_end: false,
+            lo: None,
+            eq: None,
+            hi: None,
+        }
+    }
+}
+
+/// A ternary search tree over string keys.
+///
+/// Each node holds one character and three children: `lo` and `hi` for
+/// keys whose character at this position is smaller or larger, and `eq` for
+/// the next character of keys that match. Unlike a trie with one child slot
+/// per possible character, a node only costs three links, and keys that
+/// share a prefix share its nodes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::tst::TernarySearchTree;
+///
+/// let mut tree = TernarySearchTree::new();
+/// for word in ["car", "cart", "care", "cat", "dog", "do"] {
+///     tree.insert(word);
+/// }
+/// assert_eq!(tree.len(), 6);
+///
+/// assert!(tree.contains("cart"));
+/// assert!(tree.contains("do"));
+/// assert!(!tree.contains("ca"));
+/// assert!(!tree.contains("carts"));
+///
+/// // "ca" is not a word itself, but still works as a prefix
+/// assert_eq!(tree.prefix_search("ca"), vec!["car", "care", "cart", "cat"]);
+/// assert_eq!(tree.prefix_search("car"), vec!["car", "care", "cart"]);
+/// assert_eq!(tree.prefix_search("do"), vec!["do", "dog"]);
+/// assert!(tree.prefix_search("x").is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct TernarySearchTree {
+    root: Link,
+    has_empty: bool,
+    length: usize,
+}
+
+impl TernarySearchTree {
+    /// Creates an empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::tst::TernarySearchTree;
+    /// let tree = TernarySearchTree::new();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        TernarySearchTree {
+            root: None,
+            has_empty: false,
+            length: 0,
+        }
+    }
+
+    /// Inserts a key, returning `false` if it was already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::tst::TernarySearchTree;
+    /// let mut tree = TernarySearchTree::new();
+    /// assert!(tree.insert("héllo"));
+    /// assert!(!tree.insert("héllo"));
+    /// assert!(tree.insert(""));
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn insert(&mut self, key: &str) -> bool {
+        let chars: Vec<char> = key.chars().collect();
+        let inserted = if chars.is_empty() {
+            !std::mem::replace(&mut self.has_empty, true)
+        } else {
+            insert(&mut self.root, &chars)
+        };
+        if inserted {
+            self.length += 1;
+        }
+        inserted
+    }
+
+    /// Returns `true` if the tree contains `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::tst::TernarySearchTree;
+    /// let mut tree = TernarySearchTree::new();
+    /// tree.insert("tea");
+    /// assert!(tree.contains("tea"));
+    /// assert!(!tree.contains("te"));
+    /// assert!(!tree.contains(""));
+    /// ```
+    pub fn contains(&self, key: &str) -> bool {
+        if key.is_empty() {
+            return self.has_empty;
+        }
+        find(&self.root, key).is_some_and(|node| node.is_end)
+    }
+
+    /// Returns every key starting with `prefix`, in sorted order.
+    ///
+    /// An empty prefix returns every key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::tst::TernarySearchTree;
+    /// let mut tree = TernarySearchTree::new();
+    /// for word in ["b", "ab", "a", "abc"] {
+    ///     tree.insert(word);
+    /// }
+    /// assert_eq!(tree.prefix_search(""), vec!["a", "ab", "abc", "b"]);
+    /// assert_eq!(tree.prefix_search("ab"), vec!["ab", "abc"]);
+    /// ```
+    pub fn prefix_search(&self, prefix: &str) -> Vec<String> {
+        let mut keys = Vec::new();
+        if prefix.is_empty() {
+            if self.has_empty {
+                keys.push(String::new());
+            }
+            collect(&self.root, &mut String::new(), &mut keys);
+            return keys;
+        }
+        if let Some(node) = find(&self.root, prefix) {
+            if node.is_end {
+                keys.push(prefix.to_string());
+            }
+            collect(&node.eq, &mut prefix.to_string(), &mut keys);
+        }
+        keys
+    }
+
+    /// Returns the number of keys in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::tst::TernarySearchTree;
+    /// let mut tree = TernarySearchTree::new();
+    /// tree.insert("a");
+    /// tree.insert("ab");
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the tree holds no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::tst::TernarySearchTree;
+    /// let mut tree = TernarySearchTree::new();
+    /// assert!(tree.is_empty());
+    /// tree.insert("a");
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+fn insert(mut link: &mut Link, chars: &[char]) -> bool {
+    let mut i = 0;
+    loop {
+        let node = link.get_or_insert_with(|| Box::new(Node::new(chars[i])));
+        link = match chars[i].cmp(&node.ch) {
+            Ordering::Less => &mut node.lo,
+            Ordering::Greater => &mut node.hi,
+            Ordering::Equal if i + 1 == chars.len() => {
+                return !std::mem::replace(&mut node.is_end, true);
+            }
+            Ordering::Equal => {
+                i += 1;
+                &mut node.eq
+            }
+        };
+    }
+}
+
+/// Returns the node holding the last character of a non-empty `key`.
+fn find<'a>(mut link: &'a Link, key: &str) -> Option<&'a Node> {
+    let mut chars = key.chars().peekable();
+    while let Some(node) = link {
+        let &ch = chars.peek()?;
+        link = match ch.cmp(&node.ch) {
+            Ordering::Less => &node.lo,
+            Ordering::Greater => &node.hi,
+            Ordering::Equal => {
+                chars.next();
+                if chars.peek().is_none() {
+                    return Some(node);
+                }
+                &node.eq
+            }
+        };
+    }
+    None
+}
+
+/// Appends every key in the subtree to `keys` in order, each prefixed by
+/// `prefix`.
+fn collect(link: &Link, prefix: &mut String, keys: &mut Vec<String>) {
+    let Some(node) = link else {
+        return;
+    };
+    collect(&node.lo, prefix, keys);
+    prefix.push(node.ch);
+    if node.is_end {
+        keys.push(prefix.clone());
+    }
+    collect(&node.eq, prefix, keys);
+    prefix.pop();
+    collect(&node.hi, prefix, keys);
+}
@@ -0,0 +1,332 @@
+#[derive(Debug)]
+struct Node<T> {
+    /// One key for a 2-node or two for a 3-node, in ascending order.
+    keys: Vec<T>,
+    /// Empty for a leaf, otherwise one more child than there are keys.
+    children: Vec<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn leaf(value: T) -> Node<T> {
+        Node {
+            keys: vec![value],
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// A 2-3 search tree.
+///
+/// Every internal node is a 2-node (one key, two children) or a 3-node (two
+/// keys, three children), and every leaf sits at the same depth. Inserting
+/// into a full 3-node temporarily makes it hold three keys; the node splits
+/// and pushes its middle key up to the parent, which may split in turn.
+/// The tree only grows taller when the root splits, so it stays perfectly
+/// balanced. Values are stored without duplicates.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::two_three::TwoThreeTree;
+///
+/// let mut tree = TwoThreeTree::new();
+/// for value in [50, 20, 80, 10, 30, 70, 90, 60] {
+///     tree.insert(value);
+/// }
+/// assert_eq!(tree.len(), 8);
+/// assert!(tree.contains(&60));
+/// assert!(!tree.contains(&65));
+/// assert_eq!(
+///     tree.iter().collect::<Vec<_>>(),
+///     vec![&10, &20, &30, &50, &60, &70, &80, &90]
+/// );
+/// ```
+///
+/// Leaves stay level and the traversal stays sorted under many
+/// insertions, including sorted runs in either direction that would degrade
+/// a plain BST:
+///
+/// ```
+/// use algo_rust::data_structs::two_three::TwoThreeTree;
+///
+/// let mut tree = TwoThreeTree::new();
+/// for value in 0..1000 {
+///     tree.insert(value);
+///     assert!(tree.is_balanced());
+/// }
+/// for value in (1000..2000).rev() {
+///     tree.insert(value);
+/// }
+/// assert!(tree.is_balanced());
+/// assert!(tree.iter().zip(tree.iter().skip(1)).all(|(a, b)| a < b));
+/// assert_eq!(tree.iter().count(), tree.len());
+/// // Every level at least doubles the capacity
+/// assert!(tree.height() <= 1 + tree.len().ilog2() as usize);
+/// ```
+#[derive(Debug)]
+pub struct TwoThreeTree<T> {
+    root: Option<Node<T>>,
+    length: usize,
+}
+
+impl<T: Ord> TwoThreeTree<T> {
+    /// Creates an empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let tree: TwoThreeTree<i32> = TwoThreeTree::new();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        TwoThreeTree {
+            root: None,
+            length: 0,
+        }
+    }
+
+    /// Inserts a value, returning `false` if it was already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// assert!(tree.insert(1));
+    /// assert!(!tree.insert(1));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        let Some(root) = &mut self.root else {
+            self.root = Some(Node::leaf(value));
+            self.length = 1;
+            return true;
+        };
+        let (inserted, split) = insert(root, value);
+        if let Some((middle, right)) = split {
+            let left = self.root.take().unwrap();
+            self.root = Some(Node {
+                keys: vec![middle],
+                children: vec![left, right],
+            });
+        }
+        if inserted {
+            self.length += 1;
+        }
+        inserted
+    }
+
+    /// Returns `true` if the tree contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// tree.insert(3);
+    /// assert!(tree.contains(&3));
+    /// assert!(!tree.contains(&4));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node = self.root.as_ref();
+        while let Some(current) = node {
+            match current.keys.binary_search(value) {
+                Ok(_) => return true,
+                Err(index) => node = current.children.get(index),
+            }
+        }
+        false
+    }
+
+    /// Returns the number of values in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the tree holds no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// assert!(tree.is_empty());
+    /// tree.insert(1);
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of levels in the tree; an empty tree has height 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.height(), 1);
+    /// // The third key splits the root
+    /// tree.insert(3);
+    /// assert_eq!(tree.height(), 2);
+    /// ```
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut node = self.root.as_ref();
+        while let Some(current) = node {
+            height += 1;
+            node = current.children.first();
+        }
+        height
+    }
+
+    /// Returns `true` if every leaf is at the same depth.
+    ///
+    /// This is the 2-3 tree's balance invariant, so it always holds; it is
+    /// exposed to make the invariant checkable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// for value in [5, 1, 9, 3, 7] {
+    ///     tree.insert(value);
+    /// }
+    /// assert!(tree.is_balanced());
+    /// ```
+    pub fn is_balanced(&self) -> bool {
+        self.root
+            .as_ref()
+            .is_none_or(|root| leaf_depth(root).is_some())
+    }
+
+    /// Creates an in-order iterator over the tree's values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::two_three::TwoThreeTree;
+    /// let mut tree = TwoThreeTree::new();
+    /// for value in [3, 1, 2] {
+    ///     tree.insert(value);
+    /// }
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        if let Some(root) = &self.root {
+            iter.push_left(root);
+        }
+        iter
+    }
+}
+
+impl<T: Ord> Default for TwoThreeTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inserts `value` below `node`, returning whether it was new and, if the
+/// node overflowed, the promoted middle key and the new right sibling.
+fn insert<T: Ord>(node: &mut Node<T>, value: T) -> (bool, Option<(T, Node<T>)>) {
+    let index = match node.keys.binary_search(&value) {
+        Ok(_) => return (false, None),
+        Err(index) => index,
+    };
+    if node.is_leaf() {
+        node.keys.insert(index, value);
+    } else {
+        let (inserted, split) = insert(&mut node.children[index], value);
+        let Some((middle, right)) = split else {
+            return (inserted, None);
+        };
+        node.keys.insert(index, middle);
+        node.children.insert(index + 1, right);
+    }
+    if node.keys.len() < 3 {
+        return (true, None);
+    }
+    let right = Node {
+        keys: node.keys.split_off(2),
+        children: if node.is_leaf() {
+            Vec::new()
+        } else {
+            node.children.split_off(2)
+        },
+    };
+    let middle = node.keys.pop().unwrap();
+    (true, Some((middle, right)))
+}
+
+/// Returns the common depth of every leaf below `node`, or `None` if the
+/// leaves are at different depths.
+fn leaf_depth<T>(node: &Node<T>) -> Option<usize> {
+    let mut depths = node.children.iter().map(leaf_depth);
+    let Some(first) = depths.next() else {
+        return Some(0);
+    };
+    let first = first?;
+    for depth in depths {
+        if depth? != first {
+            return None;
+        }
+    }
+    Some(first + 1)
+}
+
+/// An in-order iterator over a 2-3 tree's values.
+pub struct Iter<'a, T> {
+    stack: Vec<(&'a Node<T>, usize)>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: &'a Node<T>) {
+        loop {
+            self.stack.push((node, 0));
+            match node.children.first() {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(top) = self.stack.last_mut() {
+            let (node, index) = *top;
+            if index < node.keys.len() {
+                top.1 += 1;
+                if let Some(child) = node.children.get(index + 1) {
+                    self.push_left(child);
+                }
+                return Some(&node.keys[index]);
+            }
+            self.stack.pop();
+        }
+        None
+    }
+}
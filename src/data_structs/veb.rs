@@ -0,0 +1,419 @@
+/// A van Emde Boas tree holding a set of integers from `0..universe`.
+///
+/// Membership, insertion, deletion, successor and predecessor all take
+/// O(log log u) time. The universe is rounded up to a power of two, and
+/// clusters are only allocated once something is stored in them.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::veb::VebTree;
+///
+/// let mut tree = VebTree::new(16);
+///
+/// // Insert keys
+/// for key in [2, 3, 4, 5, 7, 14, 15] {
+///     tree.insert(key);
+/// }
+/// assert!(tree.member(7));
+/// assert!(!tree.member(6));
+///
+/// // Neighbours
+/// assert_eq!(tree.successor(7), Some(14));
+/// assert_eq!(tree.predecessor(2), None);
+///
+/// // Delete a key
+/// assert!(tree.delete(14));
+/// assert_eq!(tree.successor(7), Some(15));
+/// ```
+///
+/// Deleting the minimum or maximum pulls the next key up from the
+/// clusters:
+///
+/// ```
+/// use algo_rust::data_structs::veb::VebTree;
+///
+/// let mut tree = VebTree::new(256);
+/// for key in [0, 15, 16, 17, 255] {
+///     assert!(tree.insert(key));
+/// }
+/// assert!(!tree.insert(16));
+///
+/// assert!(tree.delete(0));
+/// assert_eq!(tree.min(), Some(15));
+/// assert!(tree.delete(255));
+/// assert_eq!(tree.max(), Some(17));
+/// assert!(!tree.delete(255));
+///
+/// // 15 and 16 sit in neighbouring clusters
+/// assert_eq!(tree.successor(15), Some(16));
+/// assert_eq!(tree.predecessor(16), Some(15));
+/// assert_eq!(tree.successor(17), None);
+/// assert_eq!(tree.predecessor(15), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VebTree {
+    universe: usize,
+    min: Option<usize>,
+    max: Option<usize>,
+    summary: Option<Box<VebTree>>,
+    clusters: Vec<Option<Box<VebTree>>>,
+}
+
+impl VebTree {
+    /// Creates an empty tree for keys in `0..universe`.
+    ///
+    /// The universe is rounded up to the next power of two, and to at least
+    /// two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let tree = VebTree::new(100);
+    /// assert_eq!(tree.universe(), 128);
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new(universe: usize) -> Self {
+        let universe = universe.max(2).next_power_of_two();
+        let clusters = if universe > 2 {
+            vec![None; upper_sqrt(universe)]
+        } else {
+            Vec::new()
+        };
+        VebTree {
+            universe,
+            min: None,
+            max: None,
+            summary: None,
+            clusters,
+        }
+    }
+
+    /// Returns the size of the key universe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// assert_eq!(VebTree::new(16).universe(), 16);
+    /// ```
+    pub fn universe(&self) -> usize {
+        self.universe
+    }
+
+    /// Returns `true` if the tree holds no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// assert!(tree.is_empty());
+    /// tree.insert(3);
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none()
+    }
+
+    /// Returns the smallest key in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// assert_eq!(tree.min(), None);
+    /// tree.insert(9);
+    /// tree.insert(4);
+    /// assert_eq!(tree.min(), Some(4));
+    /// ```
+    pub fn min(&self) -> Option<usize> {
+        self.min
+    }
+
+    /// Returns the largest key in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// assert_eq!(tree.max(), None);
+    /// tree.insert(9);
+    /// tree.insert(4);
+    /// assert_eq!(tree.max(), Some(9));
+    /// ```
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    /// Returns `true` if `key` is in the tree.
+    ///
+    /// Keys outside the universe are never members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// tree.insert(5);
+    /// assert!(tree.member(5));
+    /// assert!(!tree.member(6));
+    /// assert!(!tree.member(1000));
+    /// ```
+    pub fn member(&self, key: usize) -> bool {
+        if key >= self.universe {
+            return false;
+        }
+        if self.min == Some(key) || self.max == Some(key) {
+            return true;
+        }
+        if self.universe == 2 {
+            return false;
+        }
+        self.cluster(self.high(key))
+            .is_some_and(|cluster| cluster.member(self.low(key)))
+    }
+
+    /// Inserts `key`, returning `false` if it was already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is outside the universe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// assert!(tree.insert(5));
+    /// assert!(!tree.insert(5));
+    /// ```
+    pub fn insert(&mut self, key: usize) -> bool {
+        assert!(
+            key < self.universe,
+            "key {key} outside universe {}",
+            self.universe
+        );
+        if self.member(key) {
+            return false;
+        }
+        self.insert_new(key);
+        true
+    }
+
+    /// Removes `key`, returning `true` if it was present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is outside the universe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// tree.insert(5);
+    /// assert!(tree.delete(5));
+    /// assert!(!tree.delete(5));
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn delete(&mut self, key: usize) -> bool {
+        assert!(
+            key < self.universe,
+            "key {key} outside universe {}",
+            self.universe
+        );
+        if !self.member(key) {
+            return false;
+        }
+        self.delete_present(key);
+        true
+    }
+
+    /// Returns the smallest key strictly greater than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// tree.insert(3);
+    /// tree.insert(10);
+    /// assert_eq!(tree.successor(0), Some(3));
+    /// assert_eq!(tree.successor(3), Some(10));
+    /// assert_eq!(tree.successor(10), None);
+    /// ```
+    pub fn successor(&self, key: usize) -> Option<usize> {
+        if key >= self.universe {
+            return None;
+        }
+        if self.universe == 2 {
+            return (key == 0 && self.max == Some(1)).then_some(1);
+        }
+        if let Some(min) = self.min
+            && key < min
+        {
+            return Some(min);
+        }
+
+        let (high, low) = (self.high(key), self.low(key));
+        if let Some(cluster) = self.cluster(high)
+            && cluster.max.is_some_and(|max| low < max)
+        {
+            let offset = cluster.successor(low)?;
+            return Some(self.index(high, offset));
+        }
+        let next = self.summary.as_ref()?.successor(high)?;
+        let offset = self.cluster(next)?.min?;
+        Some(self.index(next, offset))
+    }
+
+    /// Returns the largest key strictly less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::veb::VebTree;
+    /// let mut tree = VebTree::new(16);
+    /// tree.insert(3);
+    /// tree.insert(10);
+    /// assert_eq!(tree.predecessor(15), Some(10));
+    /// assert_eq!(tree.predecessor(10), Some(3));
+    /// assert_eq!(tree.predecessor(3), None);
+    /// assert_eq!(tree.predecessor(1000), Some(10));
+    /// ```
+    pub fn predecessor(&self, key: usize) -> Option<usize> {
+        if key >= self.universe {
+            return self.max;
+        }
+        if self.universe == 2 {
+            return (key == 1 && self.min == Some(0)).then_some(0);
+        }
+        if let Some(max) = self.max
+            && key > max
+        {
+            return Some(max);
+        }
+
+        let (high, low) = (self.high(key), self.low(key));
+        if let Some(cluster) = self.cluster(high)
+            && cluster.min.is_some_and(|min| low > min)
+        {
+            let offset = cluster.predecessor(low)?;
+            return Some(self.index(high, offset));
+        }
+        let previous = self
+            .summary
+            .as_ref()
+            .and_then(|summary| summary.predecessor(high));
+        match previous {
+            Some(previous) => {
+                let offset = self.cluster(previous)?.max?;
+                Some(self.index(previous, offset))
+            }
+            // The minimum is kept out of the clusters.
+            None => self.min.filter(|&min| min < key),
+        }
+    }
+
+    fn insert_new(&mut self, mut key: usize) {
+        let Some(min) = self.min else {
+            self.min = Some(key);
+            self.max = Some(key);
+            return;
+        };
+        if key < min {
+            self.min = Some(key);
+            key = min;
+        }
+        if self.universe > 2 {
+            let (high, low) = (self.high(key), self.low(key));
+            let cluster_size = lower_sqrt(self.universe);
+            let summary_size = upper_sqrt(self.universe);
+            let cluster =
+                self.clusters[high].get_or_insert_with(|| Box::new(VebTree::new(cluster_size)));
+            if cluster.is_empty() {
+                self.summary
+                    .get_or_insert_with(|| Box::new(VebTree::new(summary_size)))
+                    .insert_new(high);
+            }
+            cluster.insert_new(low);
+        }
+        if self.max.is_some_and(|max| key > max) {
+            self.max = Some(key);
+        }
+    }
+
+    fn delete_present(&mut self, mut key: usize) {
+        if self.min == self.max {
+            self.min = None;
+            self.max = None;
+            return;
+        }
+        if self.universe == 2 {
+            let remaining = 1 - key;
+            self.min = Some(remaining);
+            self.max = Some(remaining);
+            return;
+        }
+
+        if self.min == Some(key) {
+            // Pull the smallest clustered key up to become the new minimum.
+            let first = self.summary.as_ref().and_then(|s| s.min).unwrap();
+            key = self.index(first, self.cluster(first).and_then(|c| c.min).unwrap());
+            self.min = Some(key);
+        }
+
+        let (high, low) = (self.high(key), self.low(key));
+        let cluster = self.clusters[high].as_mut().unwrap();
+        cluster.delete_present(low);
+        if cluster.is_empty() {
+            self.clusters[high] = None;
+            let summary = self.summary.as_mut().unwrap();
+            summary.delete_present(high);
+            if self.max == Some(key) {
+                self.max = match summary.max {
+                    Some(last) => {
+                        let offset = self.cluster(last).and_then(|c| c.max).unwrap();
+                        Some(self.index(last, offset))
+                    }
+                    None => self.min,
+                };
+            }
+        } else if self.max == Some(key) {
+            let offset = cluster.max.unwrap();
+            self.max = Some(self.index(high, offset));
+        }
+    }
+
+    fn cluster(&self, high: usize) -> Option<&VebTree> {
+        self.clusters[high].as_deref()
+    }
+
+    fn high(&self, key: usize) -> usize {
+        key / lower_sqrt(self.universe)
+    }
+
+    fn low(&self, key: usize) -> usize {
+        key % lower_sqrt(self.universe)
+    }
+
+    fn index(&self, high: usize, low: usize) -> usize {
+        high * lower_sqrt(self.universe) + low
+    }
+}
+
+/// The number of clusters for a power-of-two universe: `2^ceil(k / 2)`.
+fn upper_sqrt(universe: usize) -> usize {
+    1 << universe.trailing_zeros().div_ceil(2)
+}
+
+/// The size of each cluster for a power-of-two universe: `2^floor(k / 2)`.
+fn lower_sqrt(universe: usize) -> usize {
+    1 << (universe.trailing_zeros() / 2)
+}
@@ -0,0 +1,243 @@
+#[derive(Debug)]
+struct Node {
+    lo: i64,
+    hi: i64,
+    /// `to_left[i]` is how many of the node's first `i` values belong to
+    /// the lower half of its range. Empty for a leaf.
+    to_left: Vec<usize>,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+impl Node {
+    fn build(values: Vec<i64>, lo: i64, hi: i64) -> Node {
+        if lo == hi {
+            return Node {
+                lo,
+                hi,
+                to_left: Vec::new(),
+                left: None,
+                right: None,
+            };
+        }
+        let mid = midpoint(lo, hi);
+        let mut to_left = Vec::with_capacity(values.len() + 1);
+        to_left.push(0);
+        for &value in &values {
+            to_left.push(to_left.last().unwrap() + usize::from(value <= mid));
+        }
+        let (lower, upper): (Vec<i64>, Vec<i64>) = values.into_iter().partition(|&v| v <= mid);
+        let child = |values: Vec<i64>, lo, hi| {
+            (!values.is_empty()).then(|| Box::new(Node::build(values, lo, hi)))
+        };
+        Node {
+            lo,
+            hi,
+            to_left,
+            left: child(lower, lo, mid),
+            right: child(upper, mid + 1, hi),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.lo == self.hi
+    }
+}
+
+/// A wavelet tree answering rank and quantile queries over an integer
+/// sequence.
+///
+/// The root covers the range of values in the sequence and splits it in
+/// half. Its bit vector records, for each position, whether the value
+/// falls in the lower or upper half; the values are then partitioned in
+/// order into two child trees, recursively, until each leaf holds a single
+/// distinct value. Every node keeps prefix counts of its bit vector, so a
+/// query maps positions from one level to the next in O(1) and takes
+/// O(log σ) overall, where σ is the size of the value range.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use algo_rust::data_structs::wavelet::WaveletTree;
+///
+/// let tree = WaveletTree::new(&[3, 1, 4, 1, 5, 9, 2, 6, 5, 3]);
+///
+/// // Occurrences of a value among the first i positions
+/// assert_eq!(tree.rank(1, 4), 2);
+/// assert_eq!(tree.rank(5, 8), 1);
+/// assert_eq!(tree.rank(7, 10), 0);
+///
+/// // The k-th smallest value in positions l..r
+/// assert_eq!(tree.quantile(0, 10, 0), Some(1));
+/// assert_eq!(tree.quantile(2, 6, 2), Some(5));
+/// assert_eq!(tree.quantile(2, 6, 4), None);
+/// ```
+///
+/// Every query on a short sequence with negatives and repeats agrees with
+/// a naive computation:
+///
+/// ```
+/// use algo_rust::data_structs::wavelet::WaveletTree;
+///
+/// let values = [-2, 7, 0, -2, 3, 7, 7, -5];
+/// let tree = WaveletTree::new(&values);
+/// for r in 0..=values.len() {
+///     for value in -5..=7 {
+///         let naive = values[..r].iter().filter(|&&v| v == value).count();
+///         assert_eq!(tree.rank(value, r), naive);
+///     }
+///     for l in 0..=r {
+///         let mut window = values[l..r].to_vec();
+///         window.sort();
+///         for k in 0..=window.len() {
+///             assert_eq!(tree.quantile(l, r, k), window.get(k).copied());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WaveletTree {
+    root: Option<Node>,
+    length: usize,
+}
+
+impl WaveletTree {
+    /// Builds a wavelet tree over `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::wavelet::WaveletTree;
+    /// let tree = WaveletTree::new(&[i64::MIN, 0, i64::MAX]);
+    /// assert_eq!(tree.len(), 3);
+    /// assert_eq!(tree.quantile(0, 3, 2), Some(i64::MAX));
+    /// ```
+    pub fn new(values: &[i64]) -> Self {
+        let root = match (values.iter().min(), values.iter().max()) {
+            (Some(&lo), Some(&hi)) => Some(Node::build(values.to_vec(), lo, hi)),
+            _ => None,
+        };
+        WaveletTree {
+            root,
+            length: values.len(),
+        }
+    }
+
+    /// Returns how many times `value` occurs among the first `i` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is greater than the length of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::wavelet::WaveletTree;
+    /// let tree = WaveletTree::new(&[2, 7, 2, 2, 7]);
+    /// assert_eq!(tree.rank(2, 0), 0);
+    /// assert_eq!(tree.rank(2, 3), 2);
+    /// assert_eq!(tree.rank(7, 5), 2);
+    /// assert_eq!(tree.rank(4, 5), 0);
+    /// ```
+    pub fn rank(&self, value: i64, mut i: usize) -> usize {
+        assert!(
+            i <= self.length,
+            "position {i} out of bounds for length {}",
+            self.length
+        );
+        let mut node = match &self.root {
+            Some(root) if (root.lo..=root.hi).contains(&value) => root,
+            _ => return 0,
+        };
+        while !node.is_leaf() {
+            let child = if value <= midpoint(node.lo, node.hi) {
+                i = node.to_left[i];
+                &node.left
+            } else {
+                i -= node.to_left[i];
+                &node.right
+            };
+            match child {
+                Some(child) => node = child,
+                None => return 0,
+            }
+        }
+        i
+    }
+
+    /// Returns the `k`-th smallest value, counting from zero, among the
+    /// values at positions `l..r`, or `None` if `k >= r - l`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `l > r` or `r` is greater than the length of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::wavelet::WaveletTree;
+    /// let tree = WaveletTree::new(&[5, 1, 4, 2, 3]);
+    /// // Positions 1..4 hold [1, 4, 2]
+    /// assert_eq!(tree.quantile(1, 4, 0), Some(1));
+    /// assert_eq!(tree.quantile(1, 4, 1), Some(2));
+    /// assert_eq!(tree.quantile(1, 4, 2), Some(4));
+    /// assert_eq!(tree.quantile(1, 4, 3), None);
+    /// ```
+    pub fn quantile(&self, mut l: usize, mut r: usize, mut k: usize) -> Option<i64> {
+        assert!(l <= r, "range start {l} is after its end {r}");
+        assert!(
+            r <= self.length,
+            "position {r} out of bounds for length {}",
+            self.length
+        );
+        if k >= r - l {
+            return None;
+        }
+        let mut node = self.root.as_ref()?;
+        while !node.is_leaf() {
+            let (left_l, left_r) = (node.to_left[l], node.to_left[r]);
+            let child = if k < left_r - left_l {
+                (l, r) = (left_l, left_r);
+                &node.left
+            } else {
+                k -= left_r - left_l;
+                (l, r) = (l - left_l, r - left_r);
+                &node.right
+            };
+            node = child.as_ref()?;
+        }
+        Some(node.lo)
+    }
+
+    /// Returns the length of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::wavelet::WaveletTree;
+    /// assert_eq!(WaveletTree::new(&[1, 2, 3]).len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the sequence is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algo_rust::data_structs::wavelet::WaveletTree;
+    /// assert!(WaveletTree::new(&[]).is_empty());
+    /// assert!(!WaveletTree::new(&[1]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+/// Returns the midpoint of `lo..=hi` rounded down, without overflowing.
+fn midpoint(lo: i64, hi: i64) -> i64 {
+    ((i128::from(lo) + i128::from(hi)).div_euclid(2)) as i64
+}
@@ -1 +1,3 @@
-pub mod data_structs;
\ No newline at end of file
+pub mod algorithms;
+pub mod data_structs;
+mod rng;
\ No newline at end of file
@@ -0,0 +1,12 @@
+/// Advances a SplitMix64 generator and returns its next output.
+///
+/// SplitMix64 is cheap and well distributed even for small seeds, which
+/// makes it a good fit for seeded, reproducible priorities and samples. It
+/// is not suitable for cryptography.
+pub(crate) fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}